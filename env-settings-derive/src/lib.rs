@@ -7,8 +7,9 @@
 //! # Env Settings Derive
 
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use std::collections::HashMap;
+use std::borrow::Cow;
 use syn::parse;
 
 mod utils;
@@ -17,45 +18,160 @@ mod utils;
 #[proc_macro_derive(EnvSettings, attributes(env_settings))]
 pub fn env_settings_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree that we can manipulate
-    let input = parse(input).unwrap();
+    let input = match parse(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     // Build the trait implementation
     implement(&input)
 }
 
+/// The macro to add the `FromStr` functionality to unit-variant enums
+///
+/// Variants are matched ignoring case, `-` and `_`, so `LOG_FORMAT=Json`, `json` and `JSON`
+/// all match the `Json` variant
+#[proc_macro_derive(EnvSettingsEnum)]
+pub fn env_settings_enum_derive(input: TokenStream) -> TokenStream {
+    // Construct a representation of Rust code as a syntax tree that we can manipulate
+    let input = match parse(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    // Build the trait implementation
+    implement_enum(&input)
+}
+
+/// Implement the logic of the enum derive macro
+fn implement_enum(input: &utils::variant::EnvSettingsEnumInput) -> TokenStream {
+    let enum_name = &input.name;
+    let variants = &input.variants;
+    let variant_labels = variants
+        .iter()
+        .map(|variant| variant.to_string())
+        .collect::<Vec<String>>();
+    let normalized_variants = variant_labels
+        .iter()
+        .map(|variant_label| env_settings_utils::normalize_variant(variant_label));
+
+    let generated_impl = quote! {
+
+        impl #enum_name {
+
+            /// The accepted variants
+            pub const VARIANTS: &'static [&'static str] = &[#(#variant_labels),*];
+
+        }
+
+        impl std::str::FromStr for #enum_name {
+            type Err = env_settings_utils::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match env_settings_utils::normalize_variant(value).as_str() {
+                    #(#normalized_variants => Ok(Self::#variants),)*
+                    _ => Err(env_settings_utils::ParseEnumError { variants: Self::VARIANTS }),
+                }
+            }
+        }
+
+    };
+
+    generated_impl.into()
+}
+
 /// Implement the logic of the derive macro
 fn implement(input: &utils::input::EnvSettingsInput) -> TokenStream {
     let struct_name = &input.name;
 
     let mut new_args = Vec::new();
+    let mut new_arg_names = Vec::new();
     let mut new_impls = Vec::new();
     let mut from_env_impls = Vec::new();
     let mut from_env_args = Vec::new();
+    let mut from_env_arg_names = Vec::new();
+    let mut new_result_impls = Vec::new();
+    let mut from_env_result_impls = Vec::new();
+    let mut field_names = Vec::new();
+    let mut result_names = Vec::new();
+    let mut const_impls = Vec::new();
+    let mut warning_impls = Vec::new();
 
+    // the generated variables are prefixed, so that they do not collide with the fields and the arguments
     let mut env_variables_impls = quote! {};
     let mut file_path_impls = quote! {};
 
+    let is_relative_to_file = input.fields.iter().any(|field| {
+        matches!(
+            field,
+            utils::field::EnvSettingsField::Parsable(parsable_field)
+                if matches!(
+                    parsable_field.decoder,
+                    utils::field::Decoder::Path { relative_to_file: true }
+                )
+        )
+    });
+
+    let mut env_file = None;
     if let Some(file_path) = &input.params.file_path {
         if input.params.delay {
-            file_path_impls = quote! { env_settings_utils::load_env_file_path(#file_path)?; }
+            file_path_impls = quote! { let __env_settings_env_file = env_settings_utils::load_env_file_path(#file_path)?; };
         } else {
-            env_settings_utils::load_env_file_path(file_path).unwrap();
+            env_file = Some(env_settings_utils::load_env_file_path(file_path).unwrap());
         }
+    } else if is_relative_to_file {
+        let error_message = "`relative_to_file` requires the `file_path` parameter";
+        return syn::Error::new(struct_name.span(), error_message)
+            .to_compile_error()
+            .into();
     }
 
-    let case_insensitive = input.params.case_insensitive;
+    let (matching, matching_impl) = if input.params.normalize_separators {
+        (
+            env_settings_utils::KeyMatching::Normalized,
+            quote! { env_settings_utils::KeyMatching::Normalized },
+        )
+    } else if input.params.case_insensitive {
+        (
+            env_settings_utils::KeyMatching::CaseInsensitive,
+            quote! { env_settings_utils::KeyMatching::CaseInsensitive },
+        )
+    } else {
+        (
+            env_settings_utils::KeyMatching::Exact,
+            quote! { env_settings_utils::KeyMatching::Exact },
+        )
+    };
 
     let env_variables = if input.params.delay {
         env_variables_impls = quote! {
-            let env_variables = env_settings_utils::get_env_variables(#case_insensitive);
+            let __env_settings_env_variables = env_settings_utils::get_env_variables(#matching_impl);
         };
-        HashMap::new()
+        env_settings_utils::EnvVariables::default()
     } else {
-        env_settings_utils::get_env_variables(case_insensitive)
+        env_settings_utils::get_env_variables(matching)
     };
 
     let prefix = input.params.prefix.clone().unwrap_or_default();
 
+    // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_env_variables`
+    let interpolate_impl = if input.params.interpolate {
+        quote! {
+            let __env_settings_value_to_parse = &env_settings_utils::interpolate(__env_settings_value_to_parse, &__env_settings_env_variables)?;
+        }
+    } else {
+        quote! {}
+    };
+
+    let interpolate_value = |value_to_parse: &str, name: &syn::Ident| {
+        if input.params.interpolate {
+            env_settings_utils::interpolate(value_to_parse, &env_variables)
+                .map_err(|err| syn::Error::new(name.span(), err.to_string()))
+        } else {
+            Ok(value_to_parse.to_string())
+        }
+    };
+
     for field in &input.fields {
         match field {
             utils::field::EnvSettingsField::NonParsable(non_parsable_field) => {
@@ -63,10 +179,13 @@ fn implement(input: &utils::input::EnvSettingsInput) -> TokenStream {
                 let type_ = &non_parsable_field.type_;
                 let argument = quote! { #name: #type_ };
                 new_args.push(argument.clone());
+                new_arg_names.push(name);
                 from_env_args.push(argument);
+                from_env_arg_names.push(name);
                 let value = quote! {#name};
                 new_impls.push(value.clone());
                 from_env_impls.push(value);
+                field_names.push(name);
             }
             utils::field::EnvSettingsField::Parsable(parsable_field) => {
                 let name = &parsable_field.name;
@@ -74,89 +193,699 @@ fn implement(input: &utils::input::EnvSettingsInput) -> TokenStream {
                 let type_ = &parsable_field.type_;
                 let type_label = &parsable_field.type_label;
                 let optional_type = &parsable_field.optional_type;
+                let parse_type = &parsable_field.parse_type;
 
-                let mut env_variable = parsable_field
-                    .variable
-                    .to_owned()
-                    .unwrap_or(format!("{prefix}{name}"));
-                if case_insensitive {
-                    env_variable = env_variable.to_lowercase();
+                if input.params.delay
+                    && matches!(parsable_field.decoder, utils::field::Decoder::StaticStr)
+                {
+                    let error_message =
+                        "`&'static str` fields are supported only without the `delay` parameter";
+                    return syn::Error::new(name.span(), error_message)
+                        .to_compile_error()
+                        .into();
                 }
 
-                // the variable involved must be named `value`
-                let (optional_value_impl, default_value_impl, new_arg_impl, parse_type) =
-                    match optional_type {
-                        Some(optional_type) => (
-                            quote! { Some(value) },
-                            quote! { None },
-                            quote! { #name: #type_ },
-                            optional_type,
-                        ),
-                        None => (
-                            quote! { value },
-                            quote! { return Err(env_settings_utils::EnvSettingsError::NotExists(#env_variable)) },
-                            quote! { #name: Option<#type_> },
-                            type_,
-                        ),
+                let mut env_variable = parsable_field.variable.to_owned().unwrap_or_else(|| {
+                    match &input.params.rename_all {
+                        Some(rename_all) => format!("{prefix}{}", rename_all.apply(name_label)),
+                        None => format!("{prefix}{name}"),
+                    }
+                });
+                env_variable = matching.normalize(&env_variable);
+                let to_variable = |alias: &String| matching.normalize(alias);
+                let deprecated_aliases = parsable_field
+                    .deprecated_aliases
+                    .iter()
+                    .map(to_variable)
+                    .collect::<Vec<String>>();
+                // the variables to look up, in priority order
+                let mut variables = vec![env_variable.to_owned()];
+                for alias in parsable_field
+                    .aliases
+                    .iter()
+                    .map(to_variable)
+                    .chain(deprecated_aliases.iter().cloned())
+                {
+                    if !variables.contains(&alias) {
+                        variables.push(alias);
+                    }
+                }
+
+                // the variable involved must be named `__env_settings_value`
+                let value_impl = match &parsable_field.pointer {
+                    Some(pointer) => {
+                        let pointer_new_impl = pointer.new_impl();
+                        quote! { #pointer_new_impl(__env_settings_value) }
+                    }
+                    None => quote! { __env_settings_value },
+                };
+
+                // the variable involved must be named `__env_settings_env_variables`
+                let suggestion_impl = if input.params.delay {
+                    quote! { env_settings_utils::suggest_env_variable(&__env_settings_env_variables, #env_variable) }
+                } else {
+                    match env_settings_utils::suggest_env_variable(&env_variables, &env_variable) {
+                        Some(suggestion) => quote! { Some(#suggestion.to_string()) },
+                        None => quote! { None },
+                    }
+                };
+
+                // the variable involved must be named `__env_settings_env_file`
+                let sources_impl = match (&input.params.file_path, &env_file) {
+                    (Some(_), None) => quote! {
+                        vec![
+                            env_settings_utils::EnvSource::Environment,
+                            env_settings_utils::EnvSource::File(__env_settings_env_file.path.to_owned()),
+                        ]
+                    },
+                    (_, Some(env_file)) => {
+                        let path = env_file.path.to_string_lossy();
+                        quote! {
+                            vec![
+                                env_settings_utils::EnvSource::Environment,
+                                env_settings_utils::EnvSource::File(std::path::PathBuf::from(#path)),
+                            ]
+                        }
+                    }
+                    (None, None) => quote! { vec![env_settings_utils::EnvSource::Environment] },
+                };
+
+                // the variable involved must be named `__env_settings_value`
+                let (optional_value_impl, default_value_impl, new_arg_impl) = match optional_type {
+                    Some(_) if parsable_field.nullable => (
+                        quote! { Some(Some(#value_impl)) },
+                        quote! {
+                            {
+                                __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Unset, None);
+                                None
+                            }
+                        },
+                        quote! { #name: #type_ },
+                    ),
+                    Some(_) => (
+                        quote! { Some(#value_impl) },
+                        quote! {
+                            {
+                                __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Unset, None);
+                                None
+                            }
+                        },
+                        quote! { #name: #type_ },
+                    ),
+                    None => (
+                        value_impl,
+                        quote! {
+                            return Err(env_settings_utils::EnvSettingsError::NotExists {
+                                field: #name_label,
+                                variables: vec![#(#variables.to_string()),*],
+                                matching: #matching_impl,
+                                sources: #sources_impl,
+                                suggestion: #suggestion_impl,
+                            })
+                        },
+                        quote! { #name: Option<#type_> },
+                    ),
+                };
+
+                // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_err`
+                let convert_err_impl = if !parsable_field.decoder.is_infallible() {
+                    // the source of secret fields is replaced by its redacted message
+                    let (value_impl, source_impl) = if parsable_field.is_redacted() {
+                        let err_impl = match parsable_field.decoder {
+                            utils::field::Decoder::Array { .. }
+                            | utils::field::Decoder::Tuple { .. } => {
+                                quote! { __env_settings_err.redact() }
+                            }
+                            _ => quote! { __env_settings_err },
+                        };
+                        (
+                            quote! { env_settings_utils::REDACTED.to_string() },
+                            quote! {
+                                env_settings_utils::redact(&#err_impl.to_string(), __env_settings_value_to_parse).into()
+                            },
+                        )
+                    } else if parsable_field.decoder.is_dsn() {
+                        (
+                            quote! { env_settings_utils::Dsn::redact(__env_settings_value_to_parse) },
+                            quote! { __env_settings_err.into() },
+                        )
+                    } else {
+                        // the parsing errors that are not errors are converted to their message or a generic one
+                        (
+                            quote! { __env_settings_value_to_parse.to_owned() },
+                            quote! {
+                                {
+                                    use env_settings_utils::{
+                                        DisplaySourceKind as _, ErrorSourceKind as _,
+                                        FallbackSourceKind as _,
+                                    };
+                                    (&&&env_settings_utils::ConvertSourceRef(&__env_settings_err))
+                                        .convert_source_kind()
+                                        .convert_source(__env_settings_err)
+                                }
+                            },
+                        )
                     };
+                    // the line declaring the value is captured now, since the file may change later
+                    quote! {
+                        {
+                            let __env_settings_value = #value_impl;
+                            return Err(env_settings_utils::EnvSettingsError::Convert {
+                                field: #name_label,
+                                snippet: env_settings_utils::FileLine::capture(&__env_settings_origin, &__env_settings_value),
+                                value: __env_settings_value,
+                                origin: __env_settings_origin,
+                                type_name: #type_label,
+                                source: #source_impl,
+                            })
+                        }
+                    }
+                } else {
+                    quote! { match __env_settings_err {} }
+                };
+
+                // the variable involved must be named `__env_settings_value_to_parse`
+                let explanation_value_impl = if parsable_field.is_redacted() {
+                    quote! { Some(env_settings_utils::REDACTED.to_string()) }
+                } else if parsable_field.decoder.is_dsn() {
+                    quote! {
+                        Some(env_settings_utils::Dsn::redact(
+                            &std::ffi::OsStr::new(__env_settings_value_to_parse).to_string_lossy(),
+                        ))
+                    }
+                } else {
+                    quote! { Some(std::ffi::OsStr::new(__env_settings_value_to_parse).to_string_lossy().into_owned()) }
+                };
+
+                // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_origin`
+                let record_impl = quote! {
+                    __env_settings_explanation.record(#name_label, __env_settings_origin.clone(), #explanation_value_impl);
+                };
+
+                // the variable involved must be named `__env_settings_value_to_parse`
+                let decode_impl = match &parsable_field.decoder {
+                    utils::field::Decoder::Array {
+                        delimiter,
+                        type_,
+                        len,
+                    } => quote! {
+                        env_settings_utils::parse_array::<#type_, #len>(__env_settings_value_to_parse, #delimiter)
+                    },
+                    #[cfg(feature = "encoding")]
+                    utils::field::Decoder::Base64 => {
+                        quote! { env_settings_utils::decode_base64::<#parse_type>(__env_settings_value_to_parse) }
+                    }
+                    #[cfg(feature = "chrono")]
+                    utils::field::Decoder::Chrono { format } => {
+                        let format_impl = match format {
+                            Some(format) => quote! { Some(#format) },
+                            None => quote! { None },
+                        };
+                        quote! {
+                            env_settings_utils::parse_chrono::<#parse_type>(__env_settings_value_to_parse, #format_impl)
+                        }
+                    }
+                    #[cfg(feature = "dsn")]
+                    utils::field::Decoder::Dsn { schemes } => quote! {
+                        env_settings_utils::parse_dsn(__env_settings_value_to_parse, &[#(#schemes),*])
+                    },
+                    utils::field::Decoder::FromStr => {
+                        quote! { __env_settings_value_to_parse.parse::<#parse_type>() }
+                    }
+                    #[cfg(feature = "duration")]
+                    utils::field::Decoder::Duration => {
+                        quote! { env_settings_utils::parse_duration(__env_settings_value_to_parse) }
+                    }
+                    #[cfg(feature = "encoding")]
+                    utils::field::Decoder::Hex => {
+                        quote! { env_settings_utils::decode_hex::<#parse_type>(__env_settings_value_to_parse) }
+                    }
+                    utils::field::Decoder::OsString => {
+                        quote! { env_settings_utils::parse_os_string(__env_settings_value_to_parse) }
+                    }
+                    utils::field::Decoder::Path {
+                        relative_to_file: false,
+                    } => {
+                        quote! { env_settings_utils::parse_path(__env_settings_value_to_parse, None) }
+                    }
+                    utils::field::Decoder::Path {
+                        relative_to_file: true,
+                    } => {
+                        quote! { env_settings_utils::parse_path(__env_settings_value_to_parse, __env_settings_base_dir) }
+                    }
+                    utils::field::Decoder::StaticStr => quote! {
+                        Ok::<&'static str, std::convert::Infallible>(__env_settings_value_to_parse)
+                    },
+                    #[cfg(feature = "time")]
+                    utils::field::Decoder::Time { format } => {
+                        let format_impl = match format {
+                            Some(format) => quote! { Some(#format) },
+                            None => quote! { None },
+                        };
+                        quote! {
+                            env_settings_utils::parse_time::<#parse_type>(__env_settings_value_to_parse, #format_impl)
+                        }
+                    }
+                    utils::field::Decoder::Tuple { delimiter, types } => {
+                        let len = types.len();
+                        let values = (0..len)
+                            .map(|position| quote::format_ident!("__env_settings_value_{position}"))
+                            .collect::<Vec<_>>();
+                        let positions = 0..len;
+                        quote! {
+                            env_settings_utils::split_delimited::<#len>(__env_settings_value_to_parse, #delimiter)
+                                .and_then(|[#(#values),*]| {
+                                    Ok((#(env_settings_utils::parse_delimited::<#types>(#values, #positions)?,)*))
+                                })
+                        }
+                    }
+                };
 
-                // the variable involved must be named `value_to_parse`
-                let convert_err_impl = quote! {
-                    return Err(env_settings_utils::EnvSettingsError::Convert(
-                        #name_label,
-                        value_to_parse.to_owned(),
-                        #type_label,
-                    ))
+                let relative_to_file = matches!(
+                    parsable_field.decoder,
+                    utils::field::Decoder::Path {
+                        relative_to_file: true
+                    }
+                );
+
+                // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_env_file`
+                let (default_base_dir_impl, base_dir_impl) = if relative_to_file {
+                    (
+                        quote! { let __env_settings_base_dir = None; },
+                        quote! {
+                            let __env_settings_base_dir = __env_settings_env_file
+                                .supplies(__env_settings_env_variable, __env_settings_value_to_parse, #matching_impl)
+                                .then(|| __env_settings_env_file.dir());
+                        },
+                    )
+                } else {
+                    (quote! {}, quote! {})
+                };
+
+                let trim = parsable_field.trim || input.params.trim;
+
+                // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_env_variable`
+                let to_str_impl = if parsable_field.decoder.requires_unicode()
+                    || input.params.interpolate
+                    || trim
+                {
+                    quote! {
+                        let __env_settings_value_to_parse =
+                            env_settings_utils::to_str(__env_settings_value_to_parse, __env_settings_env_variable)?;
+                    }
+                } else {
+                    quote! {}
+                };
+
+                // the variable involved must be named `__env_settings_value_to_parse`
+                let parse_impl = quote! {
+                    match #decode_impl {
+                        Ok(__env_settings_value) => #optional_value_impl,
+                        Err(__env_settings_err) => #convert_err_impl
+                    }
                 };
+
+                // the value of the associated constant, if the field is resolved at compilation time
+                let const_value_impl =
+                    |literal: Option<TokenStream2>| match (literal, optional_type) {
+                        (Some(literal), Some(_)) => Some(quote! { Some(#literal) }),
+                        (literal, _) => literal,
+                    };
+                let mut default_const_impl = None;
+
                 let default_impl = match &parsable_field.default {
-                    Some(value_to_parse) => {
+                    Some(utils::attributes::DefaultValue::Str(value_to_parse, span))
+                        if input.params.delay =>
+                    {
+                        // interpolated defaults are known only at run time
+                        if !input.params.interpolate
+                            && let Err(err) = parsable_field.check_default(value_to_parse, *span)
+                        {
+                            return err.to_compile_error().into();
+                        }
                         quote! {
-                            match #value_to_parse.parse::<#parse_type>() {
-                                Ok(value) => #optional_value_impl,
-                                Err(_) => {
-                                    let value_to_parse = #value_to_parse;
-                                    #convert_err_impl
-                                }
+                            {
+                                let __env_settings_value_to_parse = #value_to_parse;
+                                let __env_settings_origin = env_settings_utils::Origin::Default;
+                                #default_base_dir_impl
+                                #interpolate_impl
+                                #record_impl
+                                #parse_impl
                             }
                         }
                     }
-                    None => default_value_impl,
+                    Some(utils::attributes::DefaultValue::Str(value_to_parse, span)) => {
+                        let value_to_parse = match interpolate_value(value_to_parse, name) {
+                            Ok(value_to_parse) => value_to_parse,
+                            Err(err) => return err.to_compile_error().into(),
+                        };
+                        if let Err(err) = parsable_field.check_default(&value_to_parse, *span) {
+                            return err.to_compile_error().into();
+                        }
+                        default_const_impl =
+                            const_value_impl(parsable_field.const_literal(&value_to_parse));
+                        quote! {
+                            {
+                                let __env_settings_value_to_parse = #value_to_parse;
+                                let __env_settings_origin = env_settings_utils::Origin::Default;
+                                #default_base_dir_impl
+                                #record_impl
+                                #parse_impl
+                            }
+                        }
+                    }
+                    Some(utils::attributes::DefaultValue::Lit(literal, ..)) => {
+                        if parsable_field.is_const() {
+                            default_const_impl = const_value_impl(Some(literal.to_owned()));
+                        }
+                        let value_to_parse = literal.to_string();
+                        quote! {
+                            {
+                                let __env_settings_value_to_parse = #value_to_parse;
+                                let __env_settings_origin = env_settings_utils::Origin::Default;
+                                #record_impl
+                                let __env_settings_value: #parse_type = #literal;
+                                #optional_value_impl
+                            }
+                        }
+                    }
+                    Some(utils::attributes::DefaultValue::Fn(path)) => quote! {
+                        {
+                            __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Default, None);
+                            let __env_settings_value: #parse_type = #path();
+                            #optional_value_impl
+                        }
+                    },
+                    Some(utils::attributes::DefaultValue::Expr(expr)) => quote! {
+                        {
+                            __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Default, None);
+                            let __env_settings_value: #parse_type = #expr;
+                            #optional_value_impl
+                        }
+                    },
+                    Some(utils::attributes::DefaultValue::Default) => quote! {
+                        {
+                            __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Default, None);
+                            let __env_settings_value = <#parse_type as Default>::default();
+                            #optional_value_impl
+                        }
+                    },
+                    None => {
+                        if optional_type.is_some() && parsable_field.is_const() {
+                            default_const_impl = Some(quote! { None });
+                        }
+                        default_value_impl
+                    }
                 };
 
-                // the variable involved must be named `value_to_parse`
-                let parse_impl = quote! {
-                    match value_to_parse.parse::<#parse_type>() {
-                        Ok(value) => #optional_value_impl,
-                        Err(_) => #convert_err_impl
+                // the variable involved must be named `__env_settings_value_to_parse`
+                let trim_impl = if trim {
+                    quote! { let __env_settings_value_to_parse = __env_settings_value_to_parse.trim(); }
+                } else {
+                    quote! {}
+                };
+
+                let empty_policy = parsable_field.empty.unwrap_or(input.params.empty);
+                // the variable involved must be named `__env_settings_env_variable`
+                let empty_impl = if parsable_field.nullable {
+                    Some(quote! { Some(None) })
+                } else {
+                    match empty_policy {
+                        utils::attributes::EmptyPolicy::Keep => None,
+                        utils::attributes::EmptyPolicy::Reject => Some(quote! {
+                            return Err(env_settings_utils::EnvSettingsError::Empty(
+                                __env_settings_env_variable.to_string(),
+                            ))
+                        }),
+                        utils::attributes::EmptyPolicy::Unset => Some(default_impl.clone()),
                     }
                 };
 
-                // the variable involved must be named `env_variables`
+                // the variables involved must be named `__env_settings_value_to_parse` and `__env_settings_env_variable`
+                let checked_parse_impl = match &empty_impl {
+                    Some(empty_impl) => quote! {
+                        if __env_settings_value_to_parse.is_empty() {
+                            #empty_impl
+                        } else {
+                            #parse_impl
+                        }
+                    },
+                    None => parse_impl.clone(),
+                };
+
+                let mut const_impl = None;
+
+                // the variable involved must be named `__env_settings_env_variables`
                 let env_value_impl = if input.params.delay {
+                    let deprecated_impl = if deprecated_aliases.is_empty() {
+                        quote! {}
+                    } else {
+                        quote! {
+                            if [#(#deprecated_aliases),*].contains(&__env_settings_env_variable) {
+                                env_settings_utils::warn(
+                                    env_settings_utils::EnvSettingsWarning::DeprecatedAlias(
+                                        __env_settings_env_variable.to_string(),
+                                        #env_variable.to_string(),
+                                    ),
+                                );
+                            }
+                        }
+                    };
+                    // the variables involved must be named `__env_settings_value_to_parse`, `__env_settings_env_variable` and `__env_settings_env_file`
+                    let origin_impl = if input.params.file_path.is_some() {
+                        quote! {
+                            __env_settings_env_file
+                                .origin(__env_settings_env_variable, __env_settings_value_to_parse, #matching_impl)
+                                .unwrap_or_else(|| {
+                                    env_settings_utils::Origin::Environment(__env_settings_env_variable.to_string())
+                                })
+                        }
+                    } else {
+                        quote! { env_settings_utils::Origin::Environment(__env_settings_env_variable.to_string()) }
+                    };
                     quote! {
-                        match env_variables.get(#env_variable) {
-                            Some(value_to_parse) => {#parse_impl},
+                        match env_settings_utils::find_env_variable(&__env_settings_env_variables, &[#(#variables),*])? {
+                            Some((__env_settings_env_variable, __env_settings_value_to_parse)) => {
+                                #deprecated_impl
+                                let __env_settings_origin = #origin_impl;
+                                #base_dir_impl
+                                #to_str_impl
+                                #trim_impl
+                                #interpolate_impl
+                                #record_impl
+                                #checked_parse_impl
+                            },
                             None => #default_impl,
                         }
                     }
                 } else {
-                    match env_variables.get(&env_variable) {
-                        Some(value_to_parse) => quote! {
-                            let value_to_parse = #value_to_parse;
-                            #parse_impl
-                        },
-                        None => default_impl,
+                    let variables = variables.iter().map(String::as_str).collect::<Vec<&str>>();
+                    let found =
+                        match env_settings_utils::find_env_variable(&env_variables, &variables) {
+                            Ok(found) => found,
+                            Err(err) => {
+                                return syn::Error::new(name.span(), err.to_string())
+                                    .to_compile_error()
+                                    .into();
+                            }
+                        };
+                    match found {
+                        Some((found_variable, value_to_parse)) => {
+                            // warnings are raised at compilation time too
+                            if deprecated_aliases
+                                .iter()
+                                .any(|alias| alias == found_variable)
+                            {
+                                let note = format!(
+                                    "the environment variable `{found_variable}` is deprecated, use `{env_variable}` instead"
+                                );
+                                let usage =
+                                    quote::quote_spanned! { name.span()=> deprecated_alias };
+                                warning_impls.push(quote! {
+                                    const _: () = {
+                                        #[deprecated(note = #note)]
+                                        #[allow(non_upper_case_globals)]
+                                        const deprecated_alias: () = ();
+                                        #usage
+                                    };
+                                });
+                            }
+                            // the base directory is resolved at compilation time too
+                            let base_dir_impl = match &env_file {
+                                Some(env_file)
+                                    if relative_to_file
+                                        && env_file.supplies(
+                                            found_variable,
+                                            value_to_parse,
+                                            matching,
+                                        ) =>
+                                {
+                                    let base_dir = env_file.dir().to_string_lossy();
+                                    quote! { let __env_settings_base_dir = Some(std::path::Path::new(#base_dir)); }
+                                }
+                                _ => default_base_dir_impl,
+                            };
+                            // values are embedded as string literals, or as bytes if they are not valid unicode
+                            let (value_to_parse, raw_value_impl) = match env_settings_utils::to_str(
+                                value_to_parse,
+                                found_variable,
+                            ) {
+                                Ok(value_to_parse) if trim => {
+                                    (Cow::Borrowed(value_to_parse.trim()), None)
+                                }
+                                Ok(value_to_parse) => (Cow::Borrowed(value_to_parse), None),
+                                Err(_)
+                                    if !parsable_field.decoder.requires_unicode()
+                                        && !input.params.interpolate =>
+                                {
+                                    let bytes = proc_macro2::Literal::byte_string(
+                                        value_to_parse.as_encoded_bytes(),
+                                    );
+                                    let error_message = format!(
+                                        "the environment variable `{found_variable}` is not valid unicode, it can be embedded only on unix targets, otherwise use the `delay` parameter"
+                                    );
+                                    let raw_value_impl = quote! {
+                                        {
+                                            #[cfg(unix)]
+                                            let __env_settings_value_to_parse = <std::ffi::OsStr as std::os::unix::ffi::OsStrExt>::from_bytes(#bytes);
+                                            #[cfg(not(unix))]
+                                            let __env_settings_value_to_parse: &std::ffi::OsStr = compile_error!(#error_message);
+                                            __env_settings_value_to_parse
+                                        }
+                                    };
+                                    // the lossy value is only used to tell the origin and whether it is empty
+                                    (value_to_parse.to_string_lossy(), Some(raw_value_impl))
+                                }
+                                Err(err) => {
+                                    return syn::Error::new(name.span(), err.to_string())
+                                        .to_compile_error()
+                                        .into();
+                                }
+                            };
+                            let value_to_parse = match interpolate_value(&value_to_parse, name) {
+                                Ok(value_to_parse) => value_to_parse,
+                                Err(err) => return err.to_compile_error().into(),
+                            };
+                            let is_empty = empty_impl.is_some() && value_to_parse.is_empty();
+                            const_impl = match empty_policy {
+                                utils::attributes::EmptyPolicy::Unset if is_empty => {
+                                    default_const_impl
+                                }
+                                _ if is_empty => None,
+                                _ => {
+                                    const_value_impl(parsable_field.const_literal(&value_to_parse))
+                                }
+                            };
+                            // the origin is resolved at compilation time too
+                            let origin_impl = match env_file.as_ref().and_then(|env_file| {
+                                env_file.origin(found_variable, &value_to_parse, matching)
+                            }) {
+                                Some(env_settings_utils::Origin::File {
+                                    variable,
+                                    path,
+                                    line,
+                                }) => {
+                                    let path = path.to_string_lossy();
+                                    quote! {
+                                        env_settings_utils::Origin::File {
+                                            variable: #variable.to_string(),
+                                            path: std::path::PathBuf::from(#path).into(),
+                                            line: #line,
+                                        }
+                                    }
+                                }
+                                _ => quote! {
+                                    env_settings_utils::Origin::Environment(#found_variable.to_string())
+                                },
+                            };
+                            let found_value_impl = match empty_impl {
+                                Some(empty_impl)
+                                    if is_empty
+                                        && empty_policy
+                                            == utils::attributes::EmptyPolicy::Reject
+                                        && !parsable_field.nullable =>
+                                {
+                                    quote! {
+                                        {
+                                            let __env_settings_env_variable = #found_variable;
+                                            #empty_impl
+                                        }
+                                    }
+                                }
+                                Some(empty_impl) if is_empty => empty_impl,
+                                _ => quote! {
+                                    {
+                                        #base_dir_impl
+                                        #parse_impl
+                                    }
+                                },
+                            };
+                            let value_to_parse_impl =
+                                raw_value_impl.unwrap_or_else(|| quote! { #value_to_parse });
+                            quote! {
+                                {
+                                    let __env_settings_value_to_parse = #value_to_parse_impl;
+                                    let __env_settings_origin = #origin_impl;
+                                    #record_impl
+                                    #found_value_impl
+                                }
+                            }
+                        }
+                        None => {
+                            const_impl = default_const_impl;
+                            default_impl
+                        }
                     }
                 };
 
-                new_impls.push(quote! {
-                    #name: match #name {
-                        Some(value) => #optional_value_impl,
+                if let Some(const_impl) = const_impl.filter(|_| input.params.consts) {
+                    let const_name = quote::format_ident!(
+                        "{}",
+                        name_label.trim_start_matches("r#").to_uppercase()
+                    );
+                    let const_doc = format!(
+                        "The value of the field `{name_label}` resolved at compilation time"
+                    );
+                    const_impls.push(quote! {
+                        #[doc = #const_doc]
+                        pub const #const_name: #type_ = #const_impl;
+                    });
+                }
+
+                // the arguments are used as they are
+                let new_value_impl = if optional_type.is_some() {
+                    quote! { Some(__env_settings_value) }
+                } else {
+                    quote! { __env_settings_value }
+                };
+
+                let new_value_impl = quote! {
+                    match #name {
+                        Some(__env_settings_value) => {
+                            __env_settings_explanation.record(#name_label, env_settings_utils::Origin::Argument, None);
+                            #new_value_impl
+                        }
                         None => #env_value_impl
                     }
-                });
+                };
+                new_impls.push(quote! { #name: #new_value_impl });
                 new_args.push(new_arg_impl);
+                new_arg_names.push(name);
                 from_env_impls.push(quote! { #name: #env_value_impl });
+
+                // every field is resolved in its own closure, so that the errors can be collected
+                new_result_impls.push(quote! {
+                    let #name = (|| -> env_settings_utils::EnvSettingsResult<#type_> {
+                        Ok(#new_value_impl)
+                    })();
+                });
+                from_env_result_impls.push(quote! {
+                    let #name = (|| -> env_settings_utils::EnvSettingsResult<#type_> {
+                        Ok(#env_value_impl)
+                    })();
+                });
+                field_names.push(name);
+                result_names.push(name);
             }
         }
     }
@@ -166,33 +895,91 @@ fn implement(input: &utils::input::EnvSettingsInput) -> TokenStream {
         #env_variables_impls
     };
 
+    // the errors are collected after every field has been resolved, unless `fail_fast` is set
+    let (new_instance_impl, from_env_instance_impl) = if input.params.fail_fast
+        || result_names.is_empty()
+    {
+        (
+            quote! { let __env_settings_instance = Self { #(#new_impls),* }; },
+            quote! { let __env_settings_instance = Self { #(#from_env_impls),* }; },
+        )
+    } else {
+        let collect_impl = quote! {
+            let __env_settings_instance = match (#(#result_names,)*) {
+                (#(Ok(#result_names),)*) => Self { #(#field_names),* },
+                (#(#result_names,)*) => {
+                    let __env_settings_errors = [#(#result_names.err()),*].into_iter().flatten().collect();
+                    return Err(env_settings_utils::EnvSettingsError::from_errors(__env_settings_errors));
+                }
+            };
+        };
+        (
+            quote! {
+                #(#new_result_impls)*
+                #collect_impl
+            },
+            quote! {
+                #(#from_env_result_impls)*
+                #collect_impl
+            },
+        )
+    };
+
     let generated_impl = quote! {
 
+        #(#warning_impls)*
+
         impl #struct_name {
 
-            /// Create a new instance using just the environment variables. Skipped fields must be passed.
+            #(#const_impls)*
+
+            /// Create a new __env_settings_instance using just the environment variables. Skipped fields must be passed.
             /// If something fails, it returns an `env_settings_utils::EnvSettingsError` error
             #[allow(clippy::too_many_arguments)]
             pub fn new(#(#new_args),*) -> env_settings_utils::EnvSettingsResult<Self> {
-                #pre_impls
-                let instance = Self {
-                    #(#new_impls),*
-                };
-                Ok(instance)
+                Self::new_explained(#(#new_arg_names),*).0
             }
 
-            /// Create a new instance using a combination of environment variables and parameters.
+            /// Like `new`, but it also explains where the values come from, even if it failed.
+            /// The returned `env_settings_utils::Explanation` is displayed as a table of field, variable,
+            /// source and __env_settings_value, where the secret values are redacted
+            #[allow(clippy::too_many_arguments)]
+            pub fn new_explained(
+                #(#new_args),*
+            ) -> (env_settings_utils::EnvSettingsResult<Self>, env_settings_utils::Explanation) {
+                let mut __env_settings_explanation = env_settings_utils::Explanation::new();
+                let __env_settings_result = (|| -> env_settings_utils::EnvSettingsResult<Self> {
+                    #pre_impls
+                    #new_instance_impl
+                    Ok(__env_settings_instance)
+                })();
+                (__env_settings_result, __env_settings_explanation)
+            }
+
+            /// Create a new __env_settings_instance using a combination of environment variables and parameters.
             /// More in detail, every field that can be initialized by the environment variables can be passed
             /// as parameter wrapped in an `Option` object. Then if the parameter is `Some`, it is used,
-            /// otherwise the value is recoved from the environment variables. Skipped fields must be passed.
+            /// otherwise the __env_settings_value is recoved from the environment variables. Skipped fields must be passed.
             /// If something fails, it returns an `env_settings_utils::EnvSettingsError` error
             #[allow(clippy::too_many_arguments)]
             pub fn from_env(#(#from_env_args),*) -> env_settings_utils::EnvSettingsResult<Self> {
-                #pre_impls
-                let instance = Self {
-                    #(#from_env_impls),*
-                };
-                Ok(instance)
+                Self::from_env_explained(#(#from_env_arg_names),*).0
+            }
+
+            /// Like `from_env`, but it also explains where the values come from, even if it failed.
+            /// The returned `env_settings_utils::Explanation` is displayed as a table of field, variable,
+            /// source and __env_settings_value, where the secret values are redacted
+            #[allow(clippy::too_many_arguments)]
+            pub fn from_env_explained(
+                #(#from_env_args),*
+            ) -> (env_settings_utils::EnvSettingsResult<Self>, env_settings_utils::Explanation) {
+                let mut __env_settings_explanation = env_settings_utils::Explanation::new();
+                let __env_settings_result = (|| -> env_settings_utils::EnvSettingsResult<Self> {
+                    #pre_impls
+                    #from_env_instance_impl
+                    Ok(__env_settings_instance)
+                })();
+                (__env_settings_result, __env_settings_explanation)
             }
 
         }
@@ -1,3 +1,4 @@
-mod attributes;
+pub(crate) mod attributes;
 pub(crate) mod field;
 pub(crate) mod input;
+pub(crate) mod variant;
@@ -1,2 +1,217 @@
 pub(crate) mod inner;
 pub(crate) mod outer;
+
+use crate::utils::input::ParamValue;
+
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{Error, Path, Result};
+
+/// The default value of a field, used if the environment variable is not set
+#[derive(Clone, Debug)]
+pub(crate) enum DefaultValue {
+    /// A string parsed like the environment variables, with the span of the literal
+    Str(String, Span),
+
+    /// A non-string literal, keeping its type, with its value and the span of the literal
+    Lit(TokenStream, String, Span),
+
+    /// A function called to compute the value
+    Fn(TokenStream),
+
+    /// An expression evaluated to compute the value
+    Expr(TokenStream),
+
+    /// The `Default::default` value of the type
+    Default,
+}
+
+/// How to handle an environment variable that is set but empty
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum EmptyPolicy {
+    /// Keep the empty value and parse it
+    #[default]
+    Keep,
+
+    /// Raise an error
+    Reject,
+
+    /// Treat the environment variable as not set
+    Unset,
+}
+
+/// The encoding of a binary value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Encoding {
+    /// Base64 encoding
+    Base64,
+
+    /// Hex encoding
+    Hex,
+}
+
+/// The naming strategy applied to the field names to get the environment variable names
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RenameRule {
+    /// `lowercase`
+    Lower,
+
+    /// `UPPERCASE`
+    Upper,
+
+    /// `PascalCase`
+    Pascal,
+
+    /// `camelCase`
+    Camel,
+
+    /// `snake_case`
+    Snake,
+
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+
+    /// `kebab-case`
+    Kebab,
+
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Parse the naming strategy from the `rename_all` parameter value
+    pub(crate) fn parse(param: &Option<ParamValue>) -> Result<Self> {
+        let span = param.as_ref().map_or(Span::call_site(), |param| param.span);
+        match param.as_ref().map(|param| param.value.as_str()) {
+            Some("lowercase") => Ok(RenameRule::Lower),
+            Some("UPPERCASE") => Ok(RenameRule::Upper),
+            Some("PascalCase") => Ok(RenameRule::Pascal),
+            Some("camelCase") => Ok(RenameRule::Camel),
+            Some("snake_case") => Ok(RenameRule::Snake),
+            Some("SCREAMING_SNAKE_CASE") => Ok(RenameRule::ScreamingSnake),
+            Some("kebab-case") => Ok(RenameRule::Kebab),
+            Some("SCREAMING-KEBAB-CASE") => Ok(RenameRule::ScreamingKebab),
+            _ => Err(Error::new(
+                span,
+                "`rename_all` must be one of `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, \
+                `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` or `SCREAMING-KEBAB-CASE`",
+            )),
+        }
+    }
+
+    /// Apply the naming strategy to a snake case field name
+    pub(crate) fn apply(&self, name: &str) -> String {
+        let name = name.trim_start_matches("r#");
+        let words = name.split('_').filter(|word| !word.is_empty());
+        let capitalize = |word: &str| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                })
+                .into_iter()
+                .flatten()
+                .collect::<String>()
+        };
+        match self {
+            RenameRule::Lower => name.to_lowercase(),
+            RenameRule::Upper => name.to_uppercase(),
+            RenameRule::Pascal => words.map(capitalize).collect(),
+            RenameRule::Camel => words
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Snake => words.collect::<Vec<_>>().join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+            RenameRule::Kebab => words.collect::<Vec<_>>().join("-").to_lowercase(),
+            RenameRule::ScreamingKebab => words.collect::<Vec<_>>().join("-").to_uppercase(),
+        }
+    }
+}
+
+impl Encoding {
+    /// Parse the encoding from the `encoding` parameter value
+    pub(crate) fn parse(param: &Option<ParamValue>) -> Result<Self> {
+        let span = param.as_ref().map_or(Span::call_site(), |param| param.span);
+        match param.as_ref().map(|param| param.value.as_str()) {
+            Some("base64") => Ok(Encoding::Base64),
+            Some("hex") => Ok(Encoding::Hex),
+            _ => Err(Error::new(
+                span,
+                "`encoding` must be one of `base64` or `hex`",
+            )),
+        }
+    }
+}
+
+impl DefaultValue {
+    /// Parse the default value from the `default`, `default_fn` and `default_expr` parameters
+    pub(crate) fn parse(
+        default: Option<&Option<ParamValue>>,
+        default_fn: Option<&Option<ParamValue>>,
+        default_expr: Option<&Option<ParamValue>>,
+    ) -> Result<Option<Self>> {
+        match (default, default_fn, default_expr) {
+            (None, None, None) => Ok(None),
+            (Some(Some(param)), None, None) => match &param.tokens {
+                Some(tokens) => Ok(Some(DefaultValue::Lit(
+                    tokens.to_owned(),
+                    param.value.to_owned(),
+                    param.span,
+                ))),
+                None => Ok(Some(DefaultValue::Str(param.value.to_owned(), param.span))),
+            },
+            (Some(None), None, None) => Ok(Some(DefaultValue::Default)),
+            (None, Some(Some(param)), None) => {
+                let path = syn::parse_str::<Path>(&param.value).map_err(|_| {
+                    let error_message = format!(
+                        "`default_fn` must be a function path, not `{}`",
+                        param.value
+                    );
+                    Error::new(param.span, error_message)
+                })?;
+                Ok(Some(DefaultValue::Fn(path.to_token_stream())))
+            }
+            (None, None, Some(Some(param))) => Ok(Some(DefaultValue::Expr(
+                param.tokens.to_owned().unwrap_or_default(),
+            ))),
+            (None, Some(None), None) => Err(Error::new(
+                Span::call_site(),
+                "`default_fn` requires a function path",
+            )),
+            (None, None, Some(None)) => Err(Error::new(
+                Span::call_site(),
+                "`default_expr` requires an expression",
+            )),
+            _ => Err(Error::new(
+                Span::call_site(),
+                "only one of `default`, `default_fn` and `default_expr` can be set",
+            )),
+        }
+    }
+}
+
+impl EmptyPolicy {
+    /// Parse the policy from the `empty` parameter value
+    pub(crate) fn parse(param: &Option<ParamValue>) -> Result<Self> {
+        let span = param.as_ref().map_or(Span::call_site(), |param| param.span);
+        match param.as_ref().map(|param| param.value.as_str()) {
+            Some("keep") => Ok(EmptyPolicy::Keep),
+            Some("reject") => Ok(EmptyPolicy::Reject),
+            Some("unset") => Ok(EmptyPolicy::Unset),
+            _ => Err(Error::new(
+                span,
+                "`empty` must be one of `keep`, `reject` or `unset`",
+            )),
+        }
+    }
+}
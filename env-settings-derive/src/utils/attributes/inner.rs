@@ -1,33 +1,134 @@
-use crate::utils::input::EnvSettingsInput;
+use crate::utils::{
+    attributes::{DefaultValue, EmptyPolicy, Encoding},
+    input::{EnvSettingsInput, ParamValue},
+};
 
 use syn::{Attribute, Result};
 
 /// The inner parameters of `EnvSettings` derive
 #[derive(Debug, Default)]
 pub(crate) struct EnvSettingsInnerParams {
+    /// The other environment variable names to use for the lookup, in priority order
+    pub(crate) aliases: Vec<String>,
+
     /// The default value to use if the environment variable is not set
-    pub(crate) default: Option<String>,
+    pub(crate) default: Option<DefaultValue>,
+
+    /// The deprecated environment variable names to use for the lookup, in priority order
+    pub(crate) deprecated_aliases: Vec<String>,
+
+    /// The delimiter of the values of tuple and array fields
+    pub(crate) delimiter: Option<String>,
+
+    /// Whether to parse the value as a human-readable duration
+    pub(crate) duration: bool,
+
+    /// How to handle the environment variable if it is set but empty
+    pub(crate) empty: Option<EmptyPolicy>,
+
+    /// The encoding of a binary value
+    pub(crate) encoding: Option<Encoding>,
+
+    /// The format of date and time values
+    pub(crate) format: Option<String>,
+
+    /// Whether the field is optional, even if its type is not an `Option`
+    pub(crate) optional: bool,
+
+    /// Whether to resolve relative paths against the directory of the environment variables file
+    pub(crate) relative_to_file: bool,
+
+    /// The allowed schemes of connection URLs
+    pub(crate) schemes: Vec<String>,
 
     /// The environment variable name
     pub(crate) variable: Option<String>,
 
+    /// Whether the value is a secret, that must be redacted from the errors
+    pub(crate) secret: bool,
+
     /// Whether to skip the parsing
     pub(crate) skip: bool,
+
+    /// Whether to trim the whitespaces surrounding the value
+    pub(crate) trim: bool,
 }
 
 impl EnvSettingsInnerParams {
     pub(crate) fn parse_attributes(attributes: &[Attribute]) -> Result<Self> {
         let params = EnvSettingsInput::parse_attributes(attributes)?;
         let mut env_settings_inner_params = EnvSettingsInnerParams::default();
-        if let Some(default) = params.get("default") {
-            default.clone_into(&mut env_settings_inner_params.default);
+        if let Some(aliases) = params.get("alias") {
+            env_settings_inner_params.aliases = parse_list(aliases);
+        }
+        if let Some(deprecated_aliases) = params.get("deprecated_alias") {
+            env_settings_inner_params.deprecated_aliases = parse_list(deprecated_aliases);
+        }
+        if params.contains_key("duration") {
+            env_settings_inner_params.duration = true;
+        }
+        if let Some(empty) = params.get("empty") {
+            env_settings_inner_params.empty = Some(EmptyPolicy::parse(empty)?);
+        }
+        if params.contains_key("default")
+            || params.contains_key("default_fn")
+            || params.contains_key("default_expr")
+        {
+            env_settings_inner_params.default = DefaultValue::parse(
+                params.get("default"),
+                params.get("default_fn"),
+                params.get("default_expr"),
+            )?;
+        }
+        if let Some(delimiter) = params.get("delimiter") {
+            env_settings_inner_params.delimiter = delimiter
+                .as_ref()
+                .map(|delimiter| delimiter.value.to_owned());
+        }
+        if let Some(encoding) = params.get("encoding") {
+            env_settings_inner_params.encoding = Some(Encoding::parse(encoding)?);
+        }
+        if let Some(format) = params.get("format") {
+            env_settings_inner_params.format =
+                format.as_ref().map(|format| format.value.to_owned());
+        }
+        if params.contains_key("optional") {
+            env_settings_inner_params.optional = true;
+        }
+        if params.contains_key("relative_to_file") {
+            env_settings_inner_params.relative_to_file = true;
+        }
+        if let Some(schemes) = params.get("schemes") {
+            env_settings_inner_params.schemes = parse_list(schemes);
         }
         if let Some(variable) = params.get("variable") {
-            variable.clone_into(&mut env_settings_inner_params.variable);
+            env_settings_inner_params.variable =
+                variable.as_ref().map(|variable| variable.value.to_owned());
+        }
+        if params.contains_key("secret") {
+            env_settings_inner_params.secret = true;
         }
         if params.contains_key("skip") {
             env_settings_inner_params.skip = true;
         }
+        if params.contains_key("trim") {
+            env_settings_inner_params.trim = true;
+        }
         Ok(env_settings_inner_params)
     }
 }
+
+/// Parse a comma-separated list of values
+fn parse_list(param: &Option<ParamValue>) -> Vec<String> {
+    param
+        .as_ref()
+        .map(|param| {
+            param
+                .value
+                .split(',')
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -1,6 +1,12 @@
-use crate::utils::input::EnvSettingsInput;
+use crate::utils::{
+    attributes::{EmptyPolicy, RenameRule},
+    input::EnvSettingsInput,
+};
 
-use syn::{Attribute, Result};
+use syn::{Attribute, Error, Ident, Result};
+
+/// The suffixes stripped from the struct identifier when deriving the prefix from it
+const STRUCT_SUFFIXES: &[&str] = &["Settings", "Config"];
 
 /// The outer parameters of `EnvSettings` derive
 #[derive(Debug, Default)]
@@ -8,14 +14,38 @@ pub(crate) struct EnvSettingsOuterParams {
     /// Whether the environment variables matching should be case insensitive
     pub(crate) case_insensitive: bool,
 
+    /// Whether to expose the fields resolved at compilation time as associated constants
+    pub(crate) consts: bool,
+
     /// Whether to delay the lookup for environment variables from compilation time to run time
     pub(crate) delay: bool,
 
+    /// How to handle the environment variables that are set but empty
+    pub(crate) empty: EmptyPolicy,
+
+    /// Whether to return the first error instead of collecting the errors of every field
+    pub(crate) fail_fast: bool,
+
     /// The path of the file to load
     pub(crate) file_path: Option<String>,
 
+    /// Whether to expand `${VARIABLE}` and `${VARIABLE:-fallback}` references in the values
+    pub(crate) interpolate: bool,
+
+    /// Whether the environment variables matching should be case insensitive and treat `-`, `.` and `_` as equivalent
+    pub(crate) normalize_separators: bool,
+
     /// The prefix to add the name of the struct fields to match the environment variables
     pub(crate) prefix: Option<String>,
+
+    /// Whether to derive the prefix from the struct identifier
+    pub(crate) prefix_from_struct: bool,
+
+    /// The naming strategy applied to the name of the struct fields, before adding the prefix
+    pub(crate) rename_all: Option<RenameRule>,
+
+    /// Whether to trim the whitespaces surrounding the values
+    pub(crate) trim: bool,
 }
 
 impl EnvSettingsOuterParams {
@@ -25,15 +55,88 @@ impl EnvSettingsOuterParams {
         if params.contains_key("case_insensitive") {
             env_settings_outer_params.case_insensitive = true;
         }
+        if params.contains_key("consts") {
+            env_settings_outer_params.consts = true;
+        }
         if params.contains_key("delay") {
             env_settings_outer_params.delay = true;
         }
+        if let Some(empty) = params.get("empty") {
+            env_settings_outer_params.empty = EmptyPolicy::parse(empty)?;
+        }
+        if params.contains_key("fail_fast") {
+            env_settings_outer_params.fail_fast = true;
+        }
         if let Some(file_path) = params.get("file_path") {
-            file_path.clone_into(&mut env_settings_outer_params.file_path);
+            env_settings_outer_params.file_path = file_path
+                .as_ref()
+                .map(|file_path| file_path.value.to_owned());
+        }
+        if params.contains_key("interpolate") {
+            env_settings_outer_params.interpolate = true;
+        }
+        if params.contains_key("normalize_separators") {
+            env_settings_outer_params.normalize_separators = true;
         }
         if let Some(prefix) = params.get("prefix") {
-            prefix.clone_into(&mut env_settings_outer_params.prefix);
+            env_settings_outer_params.prefix =
+                prefix.as_ref().map(|prefix| prefix.value.to_owned());
         };
+        if let Some(prefix_from_struct) = params.get("prefix_from_struct") {
+            if let Some(value) = prefix_from_struct {
+                return Err(Error::new(
+                    value.span,
+                    "`prefix_from_struct` does not take a value",
+                ));
+            }
+            if let Some(Some(prefix)) = params.get("prefix") {
+                return Err(Error::new(
+                    prefix.span,
+                    "only one of `prefix` and `prefix_from_struct` can be set",
+                ));
+            }
+            env_settings_outer_params.prefix_from_struct = true;
+        }
+        if let Some(rename_all) = params.get("rename_all") {
+            env_settings_outer_params.rename_all = Some(RenameRule::parse(rename_all)?);
+        }
+        if params.contains_key("trim") {
+            env_settings_outer_params.trim = true;
+        }
         Ok(env_settings_outer_params)
     }
+
+    /// Derive the prefix from the struct identifier, if required.
+    ///
+    /// The identifier is converted to screaming snake case, without the `Settings` or `Config` suffix
+    /// (e.g. `HttpServerSettings` becomes `HTTP_SERVER_`)
+    pub(crate) fn apply_prefix_from_struct(&mut self, name: &Ident) {
+        if !self.prefix_from_struct {
+            return;
+        }
+        let name = name.to_string();
+        let name = name.trim_start_matches("r#");
+        let name = STRUCT_SUFFIXES
+            .iter()
+            .find_map(|suffix| name.strip_suffix(suffix))
+            .filter(|name| !name.is_empty())
+            .unwrap_or(name);
+        let chars = name.chars().collect::<Vec<char>>();
+        let mut prefix = String::with_capacity(name.len() + 4);
+        for (index, char) in chars.iter().enumerate() {
+            // a word starts after a lowercase letter or a digit, or at the last letter of an acronym
+            let is_word_start = index > 0
+                && char.is_uppercase()
+                && (!chars[index - 1].is_uppercase()
+                    || chars.get(index + 1).is_some_and(|next| next.is_lowercase()));
+            if is_word_start && chars[index - 1] != '_' {
+                prefix.push('_');
+            }
+            prefix.extend(char.to_uppercase());
+        }
+        if !prefix.is_empty() && !prefix.ends_with('_') {
+            prefix.push('_');
+        }
+        self.prefix = Some(prefix);
+    }
 }
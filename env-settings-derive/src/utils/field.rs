@@ -1,8 +1,13 @@
-use crate::utils::attributes::inner::EnvSettingsInnerParams;
+use crate::utils::attributes::{
+    DefaultValue, EmptyPolicy, Encoding, inner::EnvSettingsInnerParams,
+};
 
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use std::{fmt, str::FromStr};
 use syn::{
-    Attribute, Data, Error, Fields, GenericArgument, Ident, PathArguments, PathSegment, Result,
-    Type, TypePath, punctuated, token,
+    Attribute, Data, Error, Fields, GenericArgument, Ident, PathArguments, Result, Type,
+    parse_quote,
 };
 
 /// A non parsable field
@@ -14,6 +19,184 @@ pub(crate) struct NonParsableField {
     pub(crate) type_: Type,
 }
 
+/// The strategy to use to convert the value of a parsable field
+pub(crate) enum Decoder {
+    /// Convert the value using `env_settings_utils::parse_array`
+    Array {
+        /// The delimiter of the values
+        delimiter: String,
+
+        /// The type of the values
+        type_: Box<Type>,
+
+        /// The length of the array
+        len: TokenStream,
+    },
+
+    /// Convert the value using `env_settings_utils::decode_base64`
+    #[cfg(feature = "encoding")]
+    Base64,
+
+    /// Convert the value using `env_settings_utils::parse_chrono`
+    #[cfg(feature = "chrono")]
+    Chrono {
+        /// The format of the value
+        format: Option<String>,
+    },
+
+    /// Convert the value using `std::str::FromStr`
+    FromStr,
+
+    /// Convert the value using `env_settings_utils::parse_dsn`
+    #[cfg(feature = "dsn")]
+    Dsn {
+        /// The allowed schemes
+        schemes: Vec<String>,
+    },
+
+    /// Convert the value using `env_settings_utils::parse_duration`
+    #[cfg(feature = "duration")]
+    Duration,
+
+    /// Convert the value using `env_settings_utils::decode_hex`
+    #[cfg(feature = "encoding")]
+    Hex,
+
+    /// Convert the value using `env_settings_utils::parse_os_string`
+    OsString,
+
+    /// Use the `&'static str` value embedded at compilation time as it is
+    StaticStr,
+
+    /// Convert the value using `env_settings_utils::parse_path`
+    Path {
+        /// Whether to resolve relative paths against the directory of the environment variables file
+        relative_to_file: bool,
+    },
+
+    /// Convert the value using `env_settings_utils::parse_time`
+    #[cfg(feature = "time")]
+    Time {
+        /// The format of the value
+        format: Option<String>,
+    },
+
+    /// Convert the value using `env_settings_utils::split_delimited` and `env_settings_utils::parse_delimited`
+    Tuple {
+        /// The delimiter of the values
+        delimiter: String,
+
+        /// The types of the values
+        types: Vec<Type>,
+    },
+}
+
+impl Decoder {
+    /// Whether the value must be valid unicode to be converted
+    pub(crate) fn requires_unicode(&self) -> bool {
+        !matches!(self, Decoder::OsString | Decoder::Path { .. })
+    }
+
+    /// Whether the value is a date or a time
+    pub(crate) fn is_date_time(&self) -> bool {
+        match self {
+            #[cfg(feature = "chrono")]
+            Decoder::Chrono { .. } => true,
+            #[cfg(feature = "time")]
+            Decoder::Time { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the value is a connection URL, whose password must be redacted from the errors
+    pub(crate) fn is_dsn(&self) -> bool {
+        match self {
+            #[cfg(feature = "dsn")]
+            Decoder::Dsn { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the conversion of the value cannot fail
+    pub(crate) fn is_infallible(&self) -> bool {
+        matches!(
+            self,
+            Decoder::OsString | Decoder::Path { .. } | Decoder::StaticStr
+        )
+    }
+
+    /// Whether the value must be redacted from the errors, since it is a key
+    pub(crate) fn is_redacted(&self) -> bool {
+        match self {
+            #[cfg(feature = "encoding")]
+            Decoder::Base64 | Decoder::Hex => true,
+            _ => false,
+        }
+    }
+}
+
+/// Check that the value can be parsed as `T`
+fn check_parse<T: FromStr>(value: &str) -> std::result::Result<(), String>
+where
+    T::Err: fmt::Display,
+{
+    value
+        .parse::<T>()
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Whether the type label is a primitive type, whose literals can be used as they are
+fn is_primitive(type_label: &str) -> bool {
+    matches!(
+        type_label,
+        "bool"
+            | "char"
+            | "f32"
+            | "f64"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+    )
+}
+
+/// Get the literal of the value, if it can be parsed as `T`
+fn parse_literal<T: FromStr + ToTokens>(value: &str) -> Option<TokenStream> {
+    value.parse::<T>().ok().map(|value| value.to_token_stream())
+}
+
+/// A smart pointer wrapping the value of a parsable field
+pub(crate) enum Pointer {
+    /// `std::sync::Arc`
+    Arc,
+
+    /// `std::boxed::Box`
+    Box,
+
+    /// `std::rc::Rc`
+    Rc,
+}
+
+impl Pointer {
+    /// The function to wrap the value in the smart pointer
+    pub(crate) fn new_impl(&self) -> TokenStream {
+        match self {
+            Pointer::Arc => quote! { std::sync::Arc::new },
+            Pointer::Box => quote! { std::boxed::Box::new },
+            Pointer::Rc => quote! { std::rc::Rc::new },
+        }
+    }
+}
+
 /// A parsable field
 pub(crate) struct ParsableField {
     /// The name of the field
@@ -29,13 +212,40 @@ pub(crate) struct ParsableField {
     pub(crate) type_label: String,
 
     /// The default value of the field
-    pub(crate) default: Option<String>,
+    pub(crate) default: Option<DefaultValue>,
+
+    /// How to handle the environment variable if it is set but empty
+    pub(crate) empty: Option<EmptyPolicy>,
+
+    /// Whether the field is an `Option<Option<T>>`, telling apart unset and empty variables
+    pub(crate) nullable: bool,
 
     /// The type specified in the option
     pub(crate) optional_type: Option<Type>,
 
+    /// The smart pointer wrapping the value
+    pub(crate) pointer: Option<Pointer>,
+
+    /// The type the value is parsed into, without the option and the smart pointer
+    pub(crate) parse_type: Type,
+
+    /// Whether the value is a secret, that must be redacted from the errors
+    pub(crate) secret: bool,
+
+    /// Whether to trim the whitespaces surrounding the value
+    pub(crate) trim: bool,
+
     /// The environment variable name
     pub(crate) variable: Option<String>,
+
+    /// The other environment variable names, in priority order
+    pub(crate) aliases: Vec<String>,
+
+    /// The deprecated environment variable names, in priority order
+    pub(crate) deprecated_aliases: Vec<String>,
+
+    /// The strategy to use to convert the value
+    pub(crate) decoder: Decoder,
 }
 
 /// The field info needed to the `EnvSettings` derive
@@ -47,6 +257,93 @@ pub(crate) enum EnvSettingsField {
     Parsable(Box<ParsableField>),
 }
 
+impl ParsableField {
+    /// Whether the value must be redacted from the errors
+    pub(crate) fn is_redacted(&self) -> bool {
+        self.secret || self.decoder.is_redacted()
+    }
+
+    /// Whether the field can be an associated constant, when resolved at compilation time
+    pub(crate) fn is_const(&self) -> bool {
+        !self.secret
+            && self.pointer.is_none()
+            && !self.nullable
+            && match self.decoder {
+                Decoder::StaticStr => true,
+                Decoder::FromStr => is_primitive(&self.type_label),
+                _ => false,
+            }
+    }
+
+    /// Get the literal of a value resolved at compilation time, if the field can be an associated constant
+    pub(crate) fn const_literal(&self, value: &str) -> Option<TokenStream> {
+        if !self.is_const() {
+            return None;
+        }
+        match self.type_label.as_str() {
+            _ if matches!(self.decoder, Decoder::StaticStr) => Some(quote! { #value }),
+            "bool" => parse_literal::<bool>(value),
+            "char" => parse_literal::<char>(value),
+            "f32" => value
+                .parse::<f32>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| quote! { #value }),
+            "f64" => value
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .map(|value| quote! { #value }),
+            "i8" => parse_literal::<i8>(value),
+            "i16" => parse_literal::<i16>(value),
+            "i32" => parse_literal::<i32>(value),
+            "i64" => parse_literal::<i64>(value),
+            "i128" => parse_literal::<i128>(value),
+            "isize" => parse_literal::<isize>(value),
+            "u8" => parse_literal::<u8>(value),
+            "u16" => parse_literal::<u16>(value),
+            "u32" => parse_literal::<u32>(value),
+            "u64" => parse_literal::<u64>(value),
+            "u128" => parse_literal::<u128>(value),
+            "usize" => parse_literal::<usize>(value),
+            _ => None,
+        }
+    }
+
+    /// Check a string default value at compilation time, if the type is a well-known primitive
+    pub(crate) fn check_default(&self, value: &str, span: Span) -> Result<()> {
+        if !matches!(self.decoder, Decoder::FromStr) {
+            return Ok(());
+        }
+        let result = match self.type_label.as_str() {
+            "bool" => check_parse::<bool>(value),
+            "char" => check_parse::<char>(value),
+            "f32" => check_parse::<f32>(value),
+            "f64" => check_parse::<f64>(value),
+            "i8" => check_parse::<i8>(value),
+            "i16" => check_parse::<i16>(value),
+            "i32" => check_parse::<i32>(value),
+            "i64" => check_parse::<i64>(value),
+            "i128" => check_parse::<i128>(value),
+            "isize" => check_parse::<isize>(value),
+            "u8" => check_parse::<u8>(value),
+            "u16" => check_parse::<u16>(value),
+            "u32" => check_parse::<u32>(value),
+            "u64" => check_parse::<u64>(value),
+            "u128" => check_parse::<u128>(value),
+            "usize" => check_parse::<usize>(value),
+            _ => Ok(()),
+        };
+        result.map_err(|err| {
+            let error_message = format!(
+                "invalid default `{value}` for the field `{}` of type `{}`: {err}",
+                self.name_label, self.type_label
+            );
+            Error::new(span, error_message)
+        })
+    }
+}
+
 impl EnvSettingsField {
     fn get_field(type_: &Type, name: &Ident, attrs: &[Attribute]) -> Result<Self> {
         let params = EnvSettingsInnerParams::parse_attributes(attrs)?;
@@ -59,10 +356,14 @@ impl EnvSettingsField {
             non_parsable_field
         } else {
             match &type_ {
-                Type::Path(type_path) => {
-                    Self::get_field_from_type_path(type_, type_path, name, params)?
+                Type::Path(_) => Self::get_field_from_type_path(type_, name, params)?,
+                Type::Array(_) | Type::Reference(_) | Type::Tuple(_) => {
+                    Self::get_parsable_field(type_, None, false, name, params)?
+                }
+                _ => {
+                    let type_label = Self::get_type_label(type_);
+                    return Err(Self::get_unsupported_error(&type_label, name));
                 }
-                _ => non_parsable_field,
             }
         };
         Ok(field)
@@ -70,42 +371,299 @@ impl EnvSettingsField {
 
     fn get_field_from_type_path(
         type_: &Type,
-        type_path: &TypePath,
         name: &Ident,
         params: EnvSettingsInnerParams,
     ) -> Result<Self> {
-        let mut segments = type_path.path.segments.to_owned();
-        let optional_type = Self::get_optional_type(&segments);
-        if let Some(Type::Path(optional_type_path)) = &optional_type {
-            optional_type_path.path.segments.clone_into(&mut segments);
+        let mut optional_type = Self::get_optional_type(type_);
+        let mut nullable = false;
+        if let Some(nullable_type) = optional_type.as_ref().and_then(Self::get_optional_type) {
+            nullable = true;
+            optional_type = Some(nullable_type);
+        }
+        if optional_type.is_none() && params.optional {
+            // the inner type of aliases is resolved by the compiler
+            optional_type = Some(parse_quote! { <#type_ as env_settings_utils::Optional>::Inner });
+        }
+        Self::get_parsable_field(type_, optional_type, nullable, name, params)
+    }
+
+    fn get_parsable_field(
+        type_: &Type,
+        optional_type: Option<Type>,
+        nullable: bool,
+        name: &Ident,
+        params: EnvSettingsInnerParams,
+    ) -> Result<Self> {
+        let mut parse_type = optional_type.as_ref().unwrap_or(type_).to_owned();
+        let mut pointer = None;
+        if let Some((pointer_, pointee_type)) = Self::get_pointer_type(&parse_type) {
+            pointer = Some(pointer_);
+            parse_type = pointee_type;
+        }
+        let type_label = Self::get_type_label(&parse_type);
+        let decoder = Self::get_decoder(&parse_type, &type_label, name, &params)?;
+        if params.relative_to_file && !matches!(decoder, Decoder::Path { .. }) {
+            return Err(Error::new(
+                name.span(),
+                "`relative_to_file` is supported only by `PathBuf` fields",
+            ));
+        }
+        if params.encoding.is_some() && !Self::is_bytes(&parse_type) {
+            return Err(Error::new(
+                name.span(),
+                "`encoding` is supported only by `Vec<u8>` and `[u8; N]` fields",
+            ));
         }
-        let type_label = segments
-            .into_iter()
-            .map(|segment| segment.ident.to_string())
-            .collect::<Vec<String>>()
-            .join("::");
 
+        let secret =
+            params.secret || matches!(type_label.as_str(), "Secret" | "env_settings_utils::Secret");
+        // the literals of the other types are parsed like strings (e.g. `default = 24` for a `String`)
+        let default = match params.default {
+            Some(DefaultValue::Lit(_, value, span))
+                if !(matches!(decoder, Decoder::FromStr) && is_primitive(&type_label)) =>
+            {
+                Some(DefaultValue::Str(value, span))
+            }
+            default => default,
+        };
         let parsable_field = ParsableField {
             name: name.to_owned(),
             name_label: name.to_string(),
             type_: type_.to_owned(),
             type_label,
-            default: params.default,
+            default,
+            empty: params.empty,
+            nullable,
             optional_type,
+            pointer,
+            parse_type,
+            secret,
+            trim: params.trim,
             variable: params.variable,
+            aliases: params.aliases,
+            deprecated_aliases: params.deprecated_aliases,
+            decoder,
         };
         let parsable_field = EnvSettingsField::Parsable(Box::new(parsable_field));
         Ok(parsable_field)
     }
 
-    fn get_optional_type(
-        segments: &punctuated::Punctuated<PathSegment, token::PathSep>,
-    ) -> Option<Type> {
-        if segments[0].ident == "Option"
-            && let PathArguments::AngleBracketed(arguments) = &segments[0].arguments
-            && let GenericArgument::Type(optional_type) = &arguments.args[0]
+    fn get_type_label(type_: &Type) -> String {
+        match type_ {
+            Type::Path(type_path) if type_path.qself.is_some() => type_path
+                .qself
+                .as_ref()
+                .map(|qself| Self::get_type_label(&qself.ty))
+                .unwrap_or_default(),
+            Type::Path(type_path) => type_path
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<String>>()
+                .join("::"),
+            Type::Array(type_array) => format!(
+                "[{}; {}]",
+                Self::get_type_label(&type_array.elem),
+                type_array.len.to_token_stream()
+            ),
+            Type::Reference(type_reference) => format!(
+                "&{}{}{}",
+                type_reference
+                    .lifetime
+                    .as_ref()
+                    .map(|lifetime| format!("{lifetime} "))
+                    .unwrap_or_default(),
+                if type_reference.mutability.is_some() {
+                    "mut "
+                } else {
+                    ""
+                },
+                Self::get_type_label(&type_reference.elem)
+            ),
+            Type::Tuple(type_tuple) => format!(
+                "({})",
+                type_tuple
+                    .elems
+                    .iter()
+                    .map(Self::get_type_label)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => type_.to_token_stream().to_string(),
+        }
+    }
+
+    fn get_decoder(
+        type_: &Type,
+        type_label: &str,
+        name: &Ident,
+        params: &EnvSettingsInnerParams,
+    ) -> Result<Decoder> {
+        if params.delimiter.is_some() && !matches!(type_, Type::Array(_) | Type::Tuple(_)) {
+            return Err(Error::new(
+                name.span(),
+                "`delimiter` is supported only by tuple and array fields",
+            ));
+        }
+        if !params.schemes.is_empty()
+            && !Self::get_decoder(type_, type_label, name, &EnvSettingsInnerParams::default())
+                .is_ok_and(|decoder| decoder.is_dsn())
+        {
+            return Err(Error::new(
+                name.span(),
+                "`schemes` is supported only by `Dsn` fields",
+            ));
+        }
+        if params.duration {
+            #[cfg(feature = "duration")]
+            return Ok(Decoder::Duration);
+            #[cfg(not(feature = "duration"))]
+            return Err(Error::new(
+                name.span(),
+                "`duration` requires the `duration` feature",
+            ));
+        }
+        let decoder = match (type_, params.encoding) {
+            #[cfg(feature = "encoding")]
+            (_, Some(Encoding::Base64)) => Decoder::Base64,
+            #[cfg(feature = "encoding")]
+            (_, Some(Encoding::Hex)) => Decoder::Hex,
+            #[cfg(not(feature = "encoding"))]
+            (_, Some(Encoding::Base64 | Encoding::Hex)) => {
+                return Err(Error::new(
+                    name.span(),
+                    "`encoding` requires the `encoding` feature",
+                ));
+            }
+            (Type::Array(type_array), None) => Decoder::Array {
+                delimiter: params.delimiter.to_owned().unwrap_or(",".to_string()),
+                type_: type_array.elem.to_owned(),
+                len: type_array.len.to_token_stream(),
+            },
+            (Type::Tuple(type_tuple), None) if !type_tuple.elems.is_empty() => Decoder::Tuple {
+                delimiter: params.delimiter.to_owned().unwrap_or(":".to_string()),
+                types: type_tuple.elems.iter().cloned().collect(),
+            },
+            (Type::Reference(_), None) if type_label == "&'static str" => Decoder::StaticStr,
+            (Type::Path(_), None) => match type_label {
+                // a bare `DateTime`, `Date` or `Time` may be any type, so it requires the `format` parameter
+                #[cfg(feature = "chrono")]
+                "DateTime" if params.format.is_some() => Decoder::Chrono {
+                    format: params.format.to_owned(),
+                },
+                #[cfg(feature = "chrono")]
+                "NaiveDate"
+                | "NaiveTime"
+                | "NaiveDateTime"
+                | "chrono::NaiveDate"
+                | "chrono::NaiveTime"
+                | "chrono::NaiveDateTime"
+                | "chrono::DateTime" => Decoder::Chrono {
+                    format: params.format.to_owned(),
+                },
+                #[cfg(feature = "dsn")]
+                "Dsn" | "env_settings_utils::Dsn" => Decoder::Dsn {
+                    schemes: params.schemes.to_owned(),
+                },
+                // a bare `Duration` may be any type, so it requires the `duration` parameter
+                #[cfg(feature = "duration")]
+                "std::time::Duration" | "core::time::Duration" => Decoder::Duration,
+                #[cfg(feature = "time")]
+                "Date" | "Time" if params.format.is_some() => Decoder::Time {
+                    format: params.format.to_owned(),
+                },
+                #[cfg(feature = "time")]
+                "PrimitiveDateTime"
+                | "OffsetDateTime"
+                | "time::Date"
+                | "time::Time"
+                | "time::PrimitiveDateTime"
+                | "time::OffsetDateTime" => Decoder::Time {
+                    format: params.format.to_owned(),
+                },
+                "OsString" | "std::ffi::OsString" => Decoder::OsString,
+                "PathBuf" | "std::path::PathBuf" => Decoder::Path {
+                    relative_to_file: params.relative_to_file,
+                },
+                _ => Decoder::FromStr,
+            },
+            _ => return Err(Self::get_unsupported_error(type_label, name)),
+        };
+        if params.format.is_some() && !decoder.is_date_time() {
+            return Err(Error::new(
+                name.span(),
+                "`format` is supported only by date and time fields",
+            ));
+        }
+        Ok(decoder)
+    }
+
+    /// Whether the type is `Vec<u8>` or `[u8; N]`
+    fn is_bytes(type_: &Type) -> bool {
+        let element_type = match type_ {
+            Type::Array(type_array) => Some(type_array.elem.as_ref().to_owned()),
+            _ => Self::get_generic_type(type_, "Vec", &[&[], &["std", "vec"], &["alloc", "vec"]]),
+        };
+        element_type.is_some_and(|element_type| {
+            matches!(
+                Self::get_type_label(&element_type).as_str(),
+                "u8" | "std::primitive::u8" | "core::primitive::u8"
+            )
+        })
+    }
+
+    fn get_unsupported_error(type_label: &str, name: &Ident) -> Error {
+        let error_message = format!(
+            "the type `{type_label}` of the field `{name}` is not supported, the field must be `skip`ped"
+        );
+        Error::new(name.span(), error_message)
+    }
+
+    fn get_optional_type(type_: &Type) -> Option<Type> {
+        Self::get_generic_type(
+            type_,
+            "Option",
+            &[&[], &["std", "option"], &["core", "option"]],
+        )
+    }
+
+    fn get_pointer_type(type_: &Type) -> Option<(Pointer, Type)> {
+        if let Some(pointee_type) =
+            Self::get_generic_type(type_, "Arc", &[&[], &["std", "sync"], &["alloc", "sync"]])
+        {
+            Some((Pointer::Arc, pointee_type))
+        } else if let Some(pointee_type) =
+            Self::get_generic_type(type_, "Box", &[&[], &["std", "boxed"], &["alloc", "boxed"]])
+        {
+            Some((Pointer::Box, pointee_type))
+        } else {
+            Self::get_generic_type(type_, "Rc", &[&[], &["std", "rc"], &["alloc", "rc"]])
+                .map(|pointee_type| (Pointer::Rc, pointee_type))
+        }
+    }
+
+    /// Get the type argument of a generic type named `ident`, optionally fully qualified by one of the `modules`
+    fn get_generic_type(type_: &Type, ident: &str, modules: &[&[&str]]) -> Option<Type> {
+        let Type::Path(type_path) = type_ else {
+            return None;
+        };
+        let segments = &type_path.path.segments;
+        let segment = segments.last()?;
+        let module = segments
+            .iter()
+            .take(segments.len() - 1)
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<String>>();
+        if type_path.qself.is_none()
+            && segment.ident == ident
+            && modules
+                .iter()
+                .any(|expected_module| module == *expected_module)
+            && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+            && let Some(GenericArgument::Type(generic_type)) = arguments.args.first()
         {
-            Some(optional_type.to_owned())
+            Some(generic_type.to_owned())
         } else {
             None
         }
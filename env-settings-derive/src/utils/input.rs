@@ -1,8 +1,66 @@
 use crate::utils::{attributes, field};
 
-use proc_macro2::TokenTree;
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use std::collections::HashMap;
-use syn::{Attribute, DeriveInput, Error, Ident, Meta, MetaList, Result, parse};
+use syn::{
+    Attribute, DeriveInput, Error, Expr, Ident, Lit, Meta, Result, Token, parse,
+    parse::ParseStream, spanned::Spanned,
+};
+
+/// The parameters whose value is a Rust expression rather than a literal
+const EXPRESSION_PARAMS: &[&str] = &["default_expr"];
+
+/// The value of a parameter
+#[derive(Clone, Debug)]
+pub(crate) struct ParamValue {
+    /// The value, without quotes if it is a string literal
+    pub(crate) value: String,
+
+    /// The tokens of the value, kept to preserve its type if it is not a string literal
+    pub(crate) tokens: Option<TokenStream>,
+
+    /// The span of the value
+    pub(crate) span: Span,
+}
+
+impl ParamValue {
+    /// Parse the value of the `name` parameter
+    fn parse(name: &Ident, input: ParseStream) -> Result<Self> {
+        if EXPRESSION_PARAMS.contains(&name.to_string().as_str()) {
+            let expression = input
+                .parse::<Expr>()
+                .map_err(|err| Error::new(err.span(), "expression value expected"))?;
+            return Ok(ParamValue {
+                value: expression.to_token_stream().to_string(),
+                span: expression.span(),
+                tokens: Some(expression.into_token_stream()),
+            });
+        }
+        let literal = input
+            .parse::<Lit>()
+            .map_err(|err| Error::new(err.span(), "literal value expected"))?;
+        let span = literal.span();
+        let value = match &literal {
+            Lit::Str(literal) => {
+                return Ok(ParamValue {
+                    value: literal.value(),
+                    tokens: None,
+                    span,
+                });
+            }
+            Lit::Int(literal) => literal.base10_digits().to_string(),
+            Lit::Float(literal) => literal.base10_digits().to_string(),
+            Lit::Bool(literal) => literal.value.to_string(),
+            literal => literal.to_token_stream().to_string(),
+        };
+        Ok(ParamValue {
+            value,
+            tokens: Some(literal.into_token_stream()),
+            span,
+        })
+    }
+}
 
 /// The `EnvSettings` macro input
 pub(crate) struct EnvSettingsInput {
@@ -20,56 +78,26 @@ impl EnvSettingsInput {
     /// Parse the attributes of the input
     pub(crate) fn parse_attributes(
         attributes: &[Attribute],
-    ) -> Result<HashMap<String, Option<String>>> {
+    ) -> Result<HashMap<String, Option<ParamValue>>> {
         let mut params = HashMap::new();
         for attribute in attributes {
             if attribute.meta.path().is_ident("env_settings")
-                && let Meta::List(MetaList { tokens, .. }) = &attribute.meta
+                && let Meta::List(list) = &attribute.meta
             {
-                let mut tokens_iterator = tokens.clone().into_iter();
-                while let Some(token) = tokens_iterator.next() {
-                    match token {
-                        TokenTree::Ident(ident) => {
-                            if let Some(TokenTree::Punct(punct)) = tokens_iterator.next() {
-                                match punct.as_char() {
-                                    '=' => {
-                                        if let Some(TokenTree::Literal(literal)) =
-                                            tokens_iterator.next()
-                                        {
-                                            let value = literal.to_string().replace('\"', "");
-                                            params.insert(ident.to_string(), Some(value));
-                                        } else {
-                                            return Err(Error::new(
-                                                punct.span(),
-                                                "literal value expected",
-                                            ));
-                                        }
-                                    }
-                                    ',' => {
-                                        params.insert(ident.to_string(), None);
-                                    }
-                                    _ => {
-                                        let error_message =
-                                            format!("punct value `{punct}` unexpected");
-                                        return Err(Error::new(punct.span(), error_message));
-                                    }
-                                }
-                            } else {
-                                params.insert(ident.to_string(), None);
-                            }
-                        }
-                        TokenTree::Punct(punct) => {
-                            if punct.as_char() != ',' {
-                                let error_message = format!("punct value `{punct}` unexpected");
-                                return Err(Error::new(punct.span(), error_message));
-                            }
-                        }
-                        _ => {
-                            let error_message = format!("token value `{token}` unexpected");
-                            return Err(Error::new(token.span(), error_message));
-                        }
+                list.parse_nested_meta(|meta| {
+                    let name = meta
+                        .path
+                        .get_ident()
+                        .ok_or_else(|| meta.error("parameter name expected"))?
+                        .to_owned();
+                    let value = if meta.input.peek(Token![=]) {
+                        Some(ParamValue::parse(&name, meta.value()?)?)
+                    } else {
+                        None
                     };
-                }
+                    params.insert(name.to_string(), value);
+                    Ok(())
+                })?;
             }
         }
         Ok(params)
@@ -80,8 +108,9 @@ impl EnvSettingsInput {
 impl parse::Parse for EnvSettingsInput {
     fn parse(input: parse::ParseStream) -> Result<Self> {
         let ast = DeriveInput::parse(input)?;
-        let params = attributes::outer::EnvSettingsOuterParams::parse_attributes(&ast.attrs)?;
+        let mut params = attributes::outer::EnvSettingsOuterParams::parse_attributes(&ast.attrs)?;
         let name = ast.ident;
+        params.apply_prefix_from_struct(&name);
         let fields = field::EnvSettingsField::parse_fields(&ast.data)?;
         let env_settings_input = EnvSettingsInput {
             params,
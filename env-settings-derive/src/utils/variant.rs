@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Result, parse};
+
+/// The `EnvSettingsEnum` macro input
+pub(crate) struct EnvSettingsEnumInput {
+    /// The identifier of the enum
+    pub(crate) name: Ident,
+
+    /// The unit variants of the enum
+    pub(crate) variants: Vec<Ident>,
+}
+
+impl EnvSettingsEnumInput {
+    /// Parse the variants of the input
+    fn parse_variants(data: &Data) -> Result<Vec<Ident>> {
+        match data {
+            Data::Enum(_enum) => {
+                let mut variants = Vec::new();
+                let mut normalized_variants = HashMap::new();
+                for variant in &_enum.variants {
+                    if let Fields::Unit = variant.fields {
+                        let normalized_variant =
+                            env_settings_utils::normalize_variant(&variant.ident.to_string());
+                        if let Some(other_variant) =
+                            normalized_variants.insert(normalized_variant, &variant.ident)
+                        {
+                            let error_message = format!(
+                                "variant `{}` is indistinguishable from `{other_variant}`",
+                                variant.ident
+                            );
+                            return Err(Error::new(variant.ident.span(), error_message));
+                        }
+                        variants.push(variant.ident.to_owned());
+                    } else {
+                        return Err(Error::new(
+                            variant.ident.span(),
+                            "enum variants must be unit",
+                        ));
+                    }
+                }
+                Ok(variants)
+            }
+            Data::Struct(_struct) => Err(Error::new(
+                _struct.struct_token.span,
+                "struct not supported",
+            )),
+            Data::Union(_union) => Err(Error::new(_union.union_token.span, "union not supported")),
+        }
+    }
+}
+
+/// Implement the parse method for `EnvSettingsEnumInput`
+impl parse::Parse for EnvSettingsEnumInput {
+    fn parse(input: parse::ParseStream) -> Result<Self> {
+        let ast = DeriveInput::parse(input)?;
+        let name = ast.ident;
+        let variants = Self::parse_variants(&ast.data)?;
+        let env_settings_enum_input = EnvSettingsEnumInput { name, variants };
+        Ok(env_settings_enum_input)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::units;
+
+use std::{fmt, str};
+
+/// The error that may occurs while parsing a human-readable byte size
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ParseByteSizeError {
+    /// Error raised when the value is empty
+    #[error("empty byte size")]
+    Empty,
+
+    /// Error raised when the number is not valid
+    #[error("invalid number `{0}`")]
+    InvalidNumber(String),
+
+    /// Error raised when a unit is not supported
+    #[error(
+        "unknown unit `{0}`, expected a unit like `B`, `KB`, `KiB`, `MB`, `MiB`, `GB` or `GiB`"
+    )]
+    UnknownUnit(String),
+
+    /// Error raised when the byte size does not fit in `u64`
+    #[error("byte size too big")]
+    Overflow,
+}
+
+/// A number of bytes parsed from a human-readable value, like `512MiB` or `2GB`
+///
+/// Decimal units (`KB`, `MB`, `GB`, ...) are powers of 1000,
+/// binary units (`KiB`, `MiB`, `GiB`, ...) are powers of 1024 and a bare number is a number of bytes.
+/// Units are case insensitive
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// Get the number of bytes
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Get the number of bytes of a byte size unit
+fn get_unit_bytes(unit: &str) -> Option<u128> {
+    let bytes = match unit.to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1000,
+        "m" | "mb" => 1000u128.pow(2),
+        "g" | "gb" => 1000u128.pow(3),
+        "t" | "tb" => 1000u128.pow(4),
+        "p" | "pb" => 1000u128.pow(5),
+        "e" | "eb" => 1000u128.pow(6),
+        "ki" | "kib" => 1 << 10,
+        "mi" | "mib" => 1 << 20,
+        "gi" | "gib" => 1 << 30,
+        "ti" | "tib" => 1 << 40,
+        "pi" | "pib" => 1 << 50,
+        "ei" | "eib" => 1 << 60,
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+impl str::FromStr for ByteSize {
+    type Err = ParseByteSizeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ParseByteSizeError::Empty);
+        }
+        let (number, unit) = units::split_number(value);
+        let unit = unit.trim_start();
+        let unit_bytes =
+            get_unit_bytes(unit).ok_or(ParseByteSizeError::UnknownUnit(unit.to_string()))?;
+        let bytes = units::scale_number(number, unit_bytes)
+            .ok_or(ParseByteSizeError::InvalidNumber(number.to_string()))?;
+        let bytes = u64::try_from(bytes).map_err(|_| ParseByteSizeError::Overflow)?;
+        Ok(ByteSize(bytes))
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(byte_size: ByteSize) -> Self {
+        byte_size.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("512MiB", Ok(ByteSize(512 * 1024 * 1024)))]
+    #[case("2GB", Ok(ByteSize(2_000_000_000)))]
+    #[case("1.5KiB", Ok(ByteSize(1536)))]
+    #[case("10 kb", Ok(ByteSize(10_000)))]
+    #[case("42", Ok(ByteSize(42)))]
+    #[case("42B", Ok(ByteSize(42)))]
+    #[case("1Gi", Ok(ByteSize(1 << 30)))]
+    #[case("", Err(ParseByteSizeError::Empty))]
+    #[case("MiB", Err(ParseByteSizeError::InvalidNumber("".to_string())))]
+    #[case("1.2.3MB", Err(ParseByteSizeError::InvalidNumber("1.2.3".to_string())))]
+    #[case("3 bananas", Err(ParseByteSizeError::UnknownUnit("bananas".to_string())))]
+    #[case("100EiB", Err(ParseByteSizeError::Overflow))]
+    fn test_parse_byte_size(
+        #[case] value: &str,
+        #[case] expected_result: Result<ByteSize, ParseByteSizeError>,
+    ) {
+        assert_eq!(value.parse::<ByteSize>(), expected_result);
+    }
+
+    #[rstest]
+    #[case(ByteSize(1024), "1024B")]
+    fn test_display_byte_size(#[case] byte_size: ByteSize, #[case] expected_result: &str) {
+        assert_eq!(byte_size.to_string(), expected_result);
+    }
+}
@@ -0,0 +1,311 @@
+/// The error that may occurs while parsing dates and times
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[error("{message}, expected format `{format}`")]
+pub struct ParseDateTimeError {
+    /// The expected format
+    pub format: String,
+
+    /// The error message of the parsing
+    pub message: String,
+}
+
+/// The format of RFC 3339 date and times, like `1970-01-01T00:00:00Z`
+const RFC_3339: &str = "RFC 3339";
+
+/// A `chrono` date or time type that can be parsed with a `strftime`-like format
+#[cfg(feature = "chrono")]
+pub trait FromChrono: Sized {
+    /// The format used if no format is specified
+    const DEFAULT_FORMAT: &'static str;
+
+    /// Parse the value with the format, or the default one
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError>;
+}
+
+#[cfg(feature = "chrono")]
+impl FromChrono for chrono::NaiveDate {
+    const DEFAULT_FORMAT: &'static str = "%Y-%m-%d";
+
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError> {
+        Self::parse_from_str(value, format.unwrap_or(Self::DEFAULT_FORMAT))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromChrono for chrono::NaiveTime {
+    const DEFAULT_FORMAT: &'static str = "%H:%M:%S%.f";
+
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError> {
+        Self::parse_from_str(value, format.unwrap_or(Self::DEFAULT_FORMAT))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromChrono for chrono::NaiveDateTime {
+    const DEFAULT_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError> {
+        Self::parse_from_str(value, format.unwrap_or(Self::DEFAULT_FORMAT))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromChrono for chrono::DateTime<chrono::FixedOffset> {
+    const DEFAULT_FORMAT: &'static str = RFC_3339;
+
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError> {
+        match format {
+            Some(format) => Self::parse_from_str(value, format),
+            None => Self::parse_from_rfc3339(value),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromChrono for chrono::DateTime<chrono::Utc> {
+    const DEFAULT_FORMAT: &'static str = RFC_3339;
+
+    fn from_chrono(value: &str, format: Option<&str>) -> Result<Self, chrono::ParseError> {
+        chrono::DateTime::<chrono::FixedOffset>::from_chrono(value, format)
+            .map(|date_time| date_time.to_utc())
+    }
+}
+
+/// Parse a `chrono` date or time with a `strftime`-like format, like `%d/%m/%Y`.
+///
+/// By default, dates and times are parsed as RFC 3339
+#[cfg(feature = "chrono")]
+pub fn parse_chrono<T: FromChrono>(
+    value: &str,
+    format: Option<&str>,
+) -> Result<T, ParseDateTimeError> {
+    T::from_chrono(value, format).map_err(|err| ParseDateTimeError {
+        format: format.unwrap_or(T::DEFAULT_FORMAT).to_string(),
+        message: err.to_string(),
+    })
+}
+
+/// A `time` date or time type that can be parsed with a format description
+#[cfg(feature = "time")]
+pub trait FromTime: Sized {
+    /// The format used if no format is specified
+    const DEFAULT_FORMAT: &'static str;
+
+    /// Parse the value with the format, or the default one
+    fn from_time(value: &str, format: Option<&str>) -> Result<Self, String>;
+}
+
+/// Parse the value with a format description, like `[day]/[month]/[year]`
+#[cfg(feature = "time")]
+fn parse_time_description<T>(
+    value: &str,
+    format: &str,
+    parse: impl Fn(&str, &time::format_description::OwnedFormatItem) -> Result<T, time::error::Parse>,
+) -> Result<T, String> {
+    let format_description =
+        time::format_description::parse_owned::<2>(format).map_err(|err| err.to_string())?;
+    parse(value, &format_description).map_err(|err| err.to_string())
+}
+
+#[cfg(feature = "time")]
+impl FromTime for time::Date {
+    const DEFAULT_FORMAT: &'static str = "[year]-[month]-[day]";
+
+    fn from_time(value: &str, format: Option<&str>) -> Result<Self, String> {
+        parse_time_description(value, format.unwrap_or(Self::DEFAULT_FORMAT), Self::parse)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromTime for time::Time {
+    const DEFAULT_FORMAT: &'static str = "[hour]:[minute]:[second][optional [.[subsecond]]]";
+
+    fn from_time(value: &str, format: Option<&str>) -> Result<Self, String> {
+        parse_time_description(value, format.unwrap_or(Self::DEFAULT_FORMAT), Self::parse)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromTime for time::PrimitiveDateTime {
+    const DEFAULT_FORMAT: &'static str =
+        "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]";
+
+    fn from_time(value: &str, format: Option<&str>) -> Result<Self, String> {
+        parse_time_description(value, format.unwrap_or(Self::DEFAULT_FORMAT), Self::parse)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromTime for time::OffsetDateTime {
+    const DEFAULT_FORMAT: &'static str = RFC_3339;
+
+    fn from_time(value: &str, format: Option<&str>) -> Result<Self, String> {
+        match format {
+            Some(format) => parse_time_description(value, format, Self::parse),
+            None => Self::parse(value, &time::format_description::well_known::Rfc3339)
+                .map_err(|err| err.to_string()),
+        }
+    }
+}
+
+/// Parse a `time` date or time with a format description, like `[day]/[month]/[year]`.
+///
+/// By default, dates and times are parsed as RFC 3339
+#[cfg(feature = "time")]
+pub fn parse_time<T: FromTime>(value: &str, format: Option<&str>) -> Result<T, ParseDateTimeError> {
+    T::from_time(value, format).map_err(|message| ParseDateTimeError {
+        format: format.unwrap_or(T::DEFAULT_FORMAT).to_string(),
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case("1970-01-01", None, Ok("1970-01-01"))]
+    #[case("01/01/1970", Some("%d/%m/%Y"), Ok("1970-01-01"))]
+    #[case(
+        "01/01/1970",
+        None,
+        Err("input contains invalid characters, expected format `%Y-%m-%d`")
+    )]
+    #[case(
+        "1970-01-01",
+        Some("%d/%m/%Y"),
+        Err("input contains invalid characters, expected format `%d/%m/%Y`")
+    )]
+    fn test_parse_chrono_date(
+        #[case] value: &str,
+        #[case] format: Option<&str>,
+        #[case] expected_result: Result<&str, &str>,
+    ) {
+        let actual_result = parse_chrono::<chrono::NaiveDate>(value, format)
+            .map(|date| date.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(
+            actual_result,
+            expected_result.map(str::to_string).map_err(str::to_string)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[rstest]
+    #[case("1970-01-01T00:00:00Z", None, Ok("1970-01-01 00:00:00 UTC"))]
+    #[case("1970-01-01T02:00:00+02:00", None, Ok("1970-01-01 00:00:00 UTC"))]
+    #[case(
+        "01/01/1970 00:00 +0000",
+        Some("%d/%m/%Y %H:%M %z"),
+        Ok("1970-01-01 00:00:00 UTC")
+    )]
+    #[case(
+        "1970-01-01",
+        None,
+        Err("premature end of input, expected format `RFC 3339`")
+    )]
+    fn test_parse_chrono_date_time(
+        #[case] value: &str,
+        #[case] format: Option<&str>,
+        #[case] expected_result: Result<&str, &str>,
+    ) {
+        let actual_result = parse_chrono::<chrono::DateTime<chrono::Utc>>(value, format)
+            .map(|date_time| date_time.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(
+            actual_result,
+            expected_result.map(str::to_string).map_err(str::to_string)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case("1970-01-01", None, Ok("1970-01-01"))]
+    #[case("01/01/1970", Some("[day]/[month]/[year]"), Ok("1970-01-01"))]
+    #[case(
+        "01/01/1970",
+        None,
+        Err("the 'year' component could not be parsed, expected format `[year]-[month]-[day]`")
+    )]
+    #[case(
+        "01/01/1970",
+        Some("[day"),
+        Err("unclosed opening bracket at byte index 0, expected format `[day`")
+    )]
+    fn test_parse_time_date(
+        #[case] value: &str,
+        #[case] format: Option<&str>,
+        #[case] expected_result: Result<&str, &str>,
+    ) {
+        let actual_result = parse_time::<time::Date>(value, format)
+            .map(|date| date.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(
+            actual_result,
+            expected_result.map(str::to_string).map_err(str::to_string)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case("08:30:00", None, Ok("8:30:00.0"))]
+    #[case("08:30:00.5", None, Ok("8:30:00.5"))]
+    #[case("08:30", Some("[hour]:[minute]"), Ok("8:30:00.0"))]
+    fn test_parse_time_time(
+        #[case] value: &str,
+        #[case] format: Option<&str>,
+        #[case] expected_result: Result<&str, &str>,
+    ) {
+        let actual_result = parse_time::<time::Time>(value, format)
+            .map(|time| time.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(
+            actual_result,
+            expected_result.map(str::to_string).map_err(str::to_string)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[rstest]
+    #[case("1970-01-01T00:00:00Z", None, Ok("1970-01-01 0:00:00.0 +00:00:00"))]
+    #[case(
+        "1970-01-01",
+        None,
+        Err("the 'separator' component could not be parsed, expected format `RFC 3339`")
+    )]
+    fn test_parse_time_offset_date_time(
+        #[case] value: &str,
+        #[case] format: Option<&str>,
+        #[case] expected_result: Result<&str, &str>,
+    ) {
+        let actual_result = parse_time::<time::OffsetDateTime>(value, format)
+            .map(|date_time| date_time.to_string())
+            .map_err(|err| err.to_string());
+        assert_eq!(
+            actual_result,
+            expected_result.map(str::to_string).map_err(str::to_string)
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "%Y-%m-%d",
+        "input contains invalid characters",
+        "input contains invalid characters, expected format `%Y-%m-%d`"
+    )]
+    fn test_display_parse_date_time_error(
+        #[case] format: &str,
+        #[case] message: &str,
+        #[case] expected_result: &str,
+    ) {
+        let error = ParseDateTimeError {
+            format: format.to_string(),
+            message: message.to_string(),
+        };
+        assert_eq!(error.to_string(), expected_result);
+    }
+}
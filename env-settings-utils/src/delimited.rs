@@ -0,0 +1,163 @@
+use std::{fmt, str};
+
+/// The error that may occurs while parsing delimited values
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ParseDelimitedError {
+    /// Error raised when the number of values is not the expected one
+    #[error("expected {expected} values separated by `{delimiter}`, got {actual}")]
+    Length {
+        /// The delimiter of the values
+        delimiter: String,
+
+        /// The expected number of values
+        expected: usize,
+
+        /// The actual number of values
+        actual: usize,
+    },
+
+    /// Error raised when a value cannot be parsed
+    #[error("invalid value `{value}` at position {position}: {message}")]
+    Value {
+        /// The position of the value, starting from 0
+        position: usize,
+
+        /// The value that cannot be parsed
+        value: String,
+
+        /// The error message of the parsing
+        message: String,
+    },
+}
+
+impl ParseDelimitedError {
+    /// Redact the value that cannot be parsed, since it is a secret
+    pub fn redact(self) -> Self {
+        match self {
+            ParseDelimitedError::Value {
+                position, message, ..
+            } => ParseDelimitedError::Value {
+                position,
+                value: crate::REDACTED.to_string(),
+                message,
+            },
+            err => err,
+        }
+    }
+}
+
+/// Split a value in exactly `N` values separated by the delimiter, trimming the surrounding whitespaces
+pub fn split_delimited<'a, const N: usize>(
+    value: &'a str,
+    delimiter: &str,
+) -> Result<[&'a str; N], ParseDelimitedError> {
+    let values = value.split(delimiter).map(str::trim).collect::<Vec<&str>>();
+    let actual = values.len();
+    values.try_into().map_err(|_| ParseDelimitedError::Length {
+        delimiter: delimiter.to_string(),
+        expected: N,
+        actual,
+    })
+}
+
+/// Parse one of the delimited values, at the given position
+pub fn parse_delimited<T: str::FromStr>(
+    value: &str,
+    position: usize,
+) -> Result<T, ParseDelimitedError>
+where
+    T::Err: fmt::Display,
+{
+    value
+        .parse::<T>()
+        .map_err(|err| ParseDelimitedError::Value {
+            position,
+            value: value.to_string(),
+            message: err.to_string(),
+        })
+}
+
+/// Parse an array of exactly `N` values separated by the delimiter
+pub fn parse_array<T: str::FromStr, const N: usize>(
+    value: &str,
+    delimiter: &str,
+) -> Result<[T; N], ParseDelimitedError>
+where
+    T::Err: fmt::Display,
+{
+    let values = split_delimited::<N>(value, delimiter)?;
+    let mut array = Vec::with_capacity(N);
+    for (position, value) in values.into_iter().enumerate() {
+        array.push(parse_delimited(value, position)?);
+    }
+    match array.try_into() {
+        Ok(array) => Ok(array),
+        Err(_) => unreachable!("the number of values has already been checked"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("localhost:8080", ":", Ok(["localhost", "8080"]))]
+    #[case(" localhost : 8080 ", ":", Ok(["localhost", "8080"]))]
+    #[case("a, b", ",", Ok(["a", "b"]))]
+    #[case("localhost", ":", Err(ParseDelimitedError::Length { delimiter: ":".to_string(), expected: 2, actual: 1 }))]
+    #[case("a:b:c", ":", Err(ParseDelimitedError::Length { delimiter: ":".to_string(), expected: 2, actual: 3 }))]
+    fn test_split_delimited(
+        #[case] value: &str,
+        #[case] delimiter: &str,
+        #[case] expected_result: Result<[&str; 2], ParseDelimitedError>,
+    ) {
+        assert_eq!(split_delimited::<2>(value, delimiter), expected_result);
+    }
+
+    #[rstest]
+    #[case("1,2,3", Ok([1, 2, 3]))]
+    #[case("1, 2, 3", Ok([1, 2, 3]))]
+    #[case("1,2", Err(ParseDelimitedError::Length { delimiter: ",".to_string(), expected: 3, actual: 2 }))]
+    #[case("1,x,3", Err(ParseDelimitedError::Value { position: 1, value: "x".to_string(), message: "invalid digit found in string".to_string() }))]
+    fn test_parse_array(
+        #[case] value: &str,
+        #[case] expected_result: Result<[u8; 3], ParseDelimitedError>,
+    ) {
+        assert_eq!(parse_array::<u8, 3>(value, ","), expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        ParseDelimitedError::Length { delimiter: ":".to_string(), expected: 2, actual: 3 },
+        "expected 2 values separated by `:`, got 3"
+    )]
+    #[case(
+        ParseDelimitedError::Value { position: 1, value: "x".to_string(), message: "invalid digit found in string".to_string() },
+        "invalid value `x` at position 1: invalid digit found in string"
+    )]
+    fn test_display_parse_delimited_error(
+        #[case] error: ParseDelimitedError,
+        #[case] expected_result: &str,
+    ) {
+        assert_eq!(error.to_string(), expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        ParseDelimitedError::Length { delimiter: ":".to_string(), expected: 2, actual: 3 },
+        ParseDelimitedError::Length { delimiter: ":".to_string(), expected: 2, actual: 3 }
+    )]
+    #[case(
+        ParseDelimitedError::Value { position: 1, value: "x".to_string(), message: "invalid digit found in string".to_string() },
+        ParseDelimitedError::Value { position: 1, value: "***".to_string(), message: "invalid digit found in string".to_string() }
+    )]
+    fn test_redact_parse_delimited_error(
+        #[case] error: ParseDelimitedError,
+        #[case] expected_result: ParseDelimitedError,
+    ) {
+        assert_eq!(error.redact(), expected_result);
+    }
+}
@@ -0,0 +1,464 @@
+use crate::{EnvSettingsError, EnvSource, FileLine};
+
+use miette::{
+    Diagnostic, LabeledSpan, MietteError, MietteSpanContents, SourceCode, SourceSpan, SpanContents,
+};
+use std::{error, fmt, num, str};
+
+impl SourceCode for FileLine {
+    /// Read the whole line, whatever the span and the context requested
+    fn read_span<'a>(
+        &'a self,
+        _span: &SourceSpan,
+        _context_lines_before: usize,
+        _context_lines_after: usize,
+    ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
+        Ok(Box::new(MietteSpanContents::new_named(
+            self.path.to_owned(),
+            self.text.as_bytes(),
+            (0, self.text.len()).into(),
+            self.number - 1,
+            0,
+            1,
+        )))
+    }
+}
+
+/// Get the hint to fix a value that can not be converted
+fn convert_help(
+    value: &str,
+    type_name: &str,
+    source: &(dyn error::Error + Send + Sync + 'static),
+) -> Option<String> {
+    if source.is::<str::ParseBoolError>() {
+        let fix = match value.trim().to_lowercase().as_str() {
+            "1" | "y" | "yes" | "on" | "enable" | "enabled" => "true",
+            "0" | "n" | "no" | "off" | "disable" | "disabled" => "false",
+            _ => return Some("booleans accept only `true` or `false`".to_string()),
+        };
+        return Some(format!(
+            "booleans accept only `true` or `false`, set `{fix}` instead of `{value}`"
+        ));
+    }
+    if source.is::<num::ParseIntError>() || source.is::<num::ParseFloatError>() {
+        let number_length = value
+            .trim()
+            .find(|char: char| !(char.is_ascii_digit() || matches!(char, '.' | '-' | '+')))?;
+        let unit = value.trim()[number_length..].trim();
+        if number_length > 0 && unit.chars().all(char::is_alphabetic) {
+            return Some(format!(
+                "`{type_name}` accepts a plain number, remove the unit `{unit}` or use a `Duration` or `ByteSize` field"
+            ));
+        }
+        return None;
+    }
+    #[cfg(feature = "duration")]
+    if let Some(crate::ParseDurationError::MissingUnit(number)) =
+        source.downcast_ref::<crate::ParseDurationError>()
+    {
+        return Some(format!(
+            "durations need a unit, e.g. `{number}s` for seconds or `{number}ms` for milliseconds"
+        ));
+    }
+    None
+}
+
+/// Get the hint to fix a variable that is not found
+fn not_exists_help(
+    variables: &[String],
+    sources: &[EnvSource],
+    suggestion: Option<&str>,
+) -> Option<String> {
+    let variable = variables.first()?;
+    let Some(suggestion) = suggestion else {
+        let sources = sources
+            .iter()
+            .map(EnvSource::to_string)
+            .collect::<Vec<String>>()
+            .join(" or in ");
+        return Some(format!("set `{variable}` in {sources}"));
+    };
+    let prefix = variable
+        .len()
+        .checked_sub(suggestion.len())
+        .and_then(|length| variable.split_at_checked(length))
+        .filter(|(prefix, name)| {
+            prefix.ends_with(['_', '-', '.']) && name.eq_ignore_ascii_case(suggestion)
+        })
+        .map(|(prefix, _)| prefix);
+    if let Some(prefix) = prefix {
+        return Some(format!(
+            "`{suggestion}` misses the `{prefix}` prefix, rename it to `{variable}`"
+        ));
+    }
+    Some(format!("rename `{suggestion}` to `{variable}`"))
+}
+
+impl Diagnostic for EnvSettingsError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let code = match self {
+            EnvSettingsError::Ambiguous(..) => "env_settings::ambiguous",
+            EnvSettingsError::Convert { .. } => "env_settings::convert",
+            EnvSettingsError::Empty(_) => "env_settings::empty",
+            EnvSettingsError::File(..) => "env_settings::file",
+            EnvSettingsError::InterpolationCycle(_) => "env_settings::interpolation_cycle",
+            EnvSettingsError::InterpolationNotExists(_) => "env_settings::interpolation_not_exists",
+            EnvSettingsError::Multiple(_) => "env_settings::multiple",
+            EnvSettingsError::NotExists { .. } => "env_settings::not_exists",
+            EnvSettingsError::NotUnicode(_) => "env_settings::not_unicode",
+        };
+        Some(Box::new(code))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let help = match self {
+            EnvSettingsError::Ambiguous(..) => {
+                Some("keep only one of the variables, or give them the same value".to_string())
+            }
+            EnvSettingsError::Convert {
+                value,
+                type_name,
+                source,
+                ..
+            } => convert_help(value, type_name, source.as_ref()),
+            EnvSettingsError::Empty(variable) => {
+                Some(format!("set a value to `{variable}` or unset it"))
+            }
+            EnvSettingsError::File(_, dotenvy::Error::LineParse(..), _) => Some(
+                "declare one `NAME=value` per line and quote the values with spaces or `#`"
+                    .to_string(),
+            ),
+            EnvSettingsError::File(..) | EnvSettingsError::Multiple(_) => None,
+            EnvSettingsError::InterpolationCycle(_) => {
+                Some("replace one of the references of the cycle with a value".to_string())
+            }
+            EnvSettingsError::InterpolationNotExists(variable) => Some(format!(
+                "set `{variable}`, or give the reference a fallback like `${{{variable}:-value}}`"
+            )),
+            EnvSettingsError::NotExists {
+                variables,
+                sources,
+                suggestion,
+                ..
+            } => not_exists_help(variables, sources, suggestion.as_deref()),
+            EnvSettingsError::NotUnicode(_) => Some(
+                "only `OsString` and `PathBuf` fields accept values that are not valid unicode"
+                    .to_string(),
+            ),
+        };
+        help.map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        let snippet = match self {
+            EnvSettingsError::Convert { snippet, .. } | EnvSettingsError::File(_, _, snippet) => {
+                snippet.as_deref()?
+            }
+            _ => return None,
+        };
+        Some(snippet)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = match self {
+            EnvSettingsError::Convert {
+                value,
+                type_name,
+                snippet: Some(snippet),
+                ..
+            } => {
+                let offset = snippet.find(value)?;
+                LabeledSpan::new_primary_with_span(
+                    Some(format!("not a valid `{type_name}`")),
+                    (offset, value.len()),
+                )
+            }
+            EnvSettingsError::File(_, dotenvy::Error::LineParse(_, index), Some(snippet)) => {
+                let offset = (*index).min(snippet.text.len());
+                LabeledSpan::new_primary_with_span(Some("unable to parse".to_string()), (offset, 0))
+            }
+            _ => return None,
+        };
+        Some(Box::new(std::iter::once(label)))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            EnvSettingsError::Multiple(errors) => Some(Box::new(
+                errors.iter().map(|error| error as &dyn Diagnostic),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::{KeyMatching, Origin};
+    use miette::NarratableReportHandler;
+    use rstest::rstest;
+    use std::{fs, io::Write, path};
+
+    /// Render a diagnostic as plain text
+    fn render(error: &EnvSettingsError) -> String {
+        let mut rendered = String::new();
+        NarratableReportHandler::new()
+            .render_report(&mut rendered, error)
+            .expect("Error occurs while rendering the diagnostic!");
+        rendered
+    }
+
+    /// Create an environment variables file in a temp directory
+    fn create_env_file(content: &str) -> path::PathBuf {
+        let temp_dir: path::PathBuf = assert_fs::TempDir::new()
+            .expect("Error occurs while creating the test temp directory!")
+            .to_path_buf();
+        fs::create_dir_all(&temp_dir)
+            .expect("Error occurs while creating the test temp directory!");
+        let temp_file_path = temp_dir.join(".env");
+        let mut temp_file = fs::File::create(&temp_file_path)
+            .expect("Error occurs while creating the test temp file!");
+        temp_file
+            .write_all(content.as_bytes())
+            .expect("Error occurs while writing the test temp file!");
+        temp_file_path
+    }
+
+    #[rstest]
+    #[case(
+        EnvSettingsError::Ambiguous("PORT".to_string(), vec!["PORT".to_string(), "port".to_string()]),
+        "env_settings::ambiguous",
+        Some("keep only one of the variables, or give them the same value")
+    )]
+    #[case(
+        EnvSettingsError::Convert {
+            field: "debug",
+            value: "yes".to_string(),
+            origin: Origin::Environment("DEBUG".to_string()),
+            type_name: "bool",
+            source: "yes".parse::<bool>().unwrap_err().into(),
+            snippet: None,
+        },
+        "env_settings::convert",
+        Some("booleans accept only `true` or `false`, set `true` instead of `yes`")
+    )]
+    #[case(
+        EnvSettingsError::Convert {
+            field: "debug",
+            value: "maybe".to_string(),
+            origin: Origin::Environment("DEBUG".to_string()),
+            type_name: "bool",
+            source: "maybe".parse::<bool>().unwrap_err().into(),
+            snippet: None,
+        },
+        "env_settings::convert",
+        Some("booleans accept only `true` or `false`")
+    )]
+    #[case(
+        EnvSettingsError::Convert {
+            field: "timeout",
+            value: "30s".to_string(),
+            origin: Origin::Environment("TIMEOUT".to_string()),
+            type_name: "u64",
+            source: "30s".parse::<u64>().unwrap_err().into(),
+            snippet: None,
+        },
+        "env_settings::convert",
+        Some("`u64` accepts a plain number, remove the unit `s` or use a `Duration` or `ByteSize` field")
+    )]
+    #[case(
+        EnvSettingsError::Convert {
+            field: "port",
+            value: "http".to_string(),
+            origin: Origin::Environment("PORT".to_string()),
+            type_name: "u16",
+            source: "http".parse::<u16>().unwrap_err().into(),
+            snippet: None,
+        },
+        "env_settings::convert",
+        None
+    )]
+    #[case(
+        EnvSettingsError::Convert {
+            field: "port",
+            value: "***".to_string(),
+            origin: Origin::Environment("PORT".to_string()),
+            type_name: "u16",
+            source: "invalid digit found in string".into(),
+            snippet: None,
+        },
+        "env_settings::convert",
+        None
+    )]
+    #[case(
+        EnvSettingsError::Empty("PORT".to_string()),
+        "env_settings::empty",
+        Some("set a value to `PORT` or unset it")
+    )]
+    #[case(
+        EnvSettingsError::File(".env".to_string(), dotenvy::Error::LineParse("PORT 80".to_string(), 4), None),
+        "env_settings::file",
+        Some("declare one `NAME=value` per line and quote the values with spaces or `#`")
+    )]
+    #[case(
+        EnvSettingsError::InterpolationCycle(vec!["A".to_string(), "B".to_string(), "A".to_string()]),
+        "env_settings::interpolation_cycle",
+        Some("replace one of the references of the cycle with a value")
+    )]
+    #[case(
+        EnvSettingsError::InterpolationNotExists("HOME".to_string()),
+        "env_settings::interpolation_not_exists",
+        Some("set `HOME`, or give the reference a fallback like `${HOME:-value}`")
+    )]
+    #[case(
+        EnvSettingsError::Multiple(vec![EnvSettingsError::Empty("PORT".to_string()), EnvSettingsError::Empty("HOST".to_string())]),
+        "env_settings::multiple",
+        None
+    )]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "database_url",
+            variables: vec!["APP_DATABASE_URL".to_string(), "DB_URL".to_string()],
+            matching: KeyMatching::Exact,
+            sources: vec![EnvSource::Environment, EnvSource::File(path::PathBuf::from("/app/.env"))],
+            suggestion: None,
+        },
+        "env_settings::not_exists",
+        Some("set `APP_DATABASE_URL` in the process environment or in `/app/.env`")
+    )]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "database_url",
+            variables: vec!["APP_DATABASE_URL".to_string()],
+            matching: KeyMatching::Exact,
+            sources: vec![EnvSource::Environment],
+            suggestion: Some("DATABASE_URL".to_string()),
+        },
+        "env_settings::not_exists",
+        Some("`DATABASE_URL` misses the `APP_` prefix, rename it to `APP_DATABASE_URL`")
+    )]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "database_url",
+            variables: vec!["APP_DATABASE_URL".to_string()],
+            matching: KeyMatching::Exact,
+            sources: vec![EnvSource::Environment],
+            suggestion: Some("APP_DATABSE_URL".to_string()),
+        },
+        "env_settings::not_exists",
+        Some("rename `APP_DATABSE_URL` to `APP_DATABASE_URL`")
+    )]
+    #[case(
+        EnvSettingsError::NotUnicode("PORT".to_string()),
+        "env_settings::not_unicode",
+        Some("only `OsString` and `PathBuf` fields accept values that are not valid unicode")
+    )]
+    fn test_diagnostic(
+        #[case] error: EnvSettingsError,
+        #[case] expected_code: &str,
+        #[case] expected_help: Option<&str>,
+    ) {
+        assert_eq!(
+            error.code().map(|code| code.to_string()).as_deref(),
+            Some(expected_code)
+        );
+        assert_eq!(
+            error.help().map(|help| help.to_string()).as_deref(),
+            expected_help
+        );
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn test_diagnostic_duration() {
+        let error = EnvSettingsError::Convert {
+            field: "timeout",
+            value: "30".to_string(),
+            origin: Origin::Environment("TIMEOUT".to_string()),
+            type_name: "Duration",
+            source: crate::parse_duration("30").unwrap_err().into(),
+            snippet: None,
+        };
+        assert_eq!(
+            error.help().map(|help| help.to_string()).as_deref(),
+            Some("durations need a unit, e.g. `30s` for seconds or `30ms` for milliseconds")
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "80a",
+        2,
+        Some(
+            "snippet line 2: PORT = \"80a\" # http\n    label at line 2, columns 9 to 11: not a valid `u16`"
+        )
+    )]
+    #[case("***", 1, None)]
+    #[case("8080", 2, None)]
+    #[case("80a", 3, None)]
+    fn test_diagnostic_file(
+        #[case] value: &str,
+        #[case] line: usize,
+        #[case] expected_snippet: Option<&str>,
+    ) {
+        let path = create_env_file("PASSWORD=hunter2\nPORT = \"80a\" # http\n");
+        let origin = Origin::File {
+            variable: "PORT".to_string(),
+            path: path.to_owned().into(),
+            line,
+        };
+        let error = EnvSettingsError::Convert {
+            field: "port",
+            value: value.to_string(),
+            snippet: FileLine::capture(&origin, value),
+            origin,
+            type_name: "u16",
+            source: "invalid digit found in string".into(),
+        };
+        // the line is captured with the error, so later changes of the file are not shown
+        fs::write(&path, "PORT=8080\n").expect("Error occurs while writing the test temp file!");
+        let rendered = render(&error);
+        assert!(!rendered.contains("hunter2"));
+        match expected_snippet {
+            Some(expected_snippet) => assert!(rendered.contains(expected_snippet), "{rendered}"),
+            None => assert!(error.source_code().is_none() && !rendered.contains("snippet")),
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_file_parse() {
+        let path = create_env_file("HOST=localhost\nPORT 80\n");
+        let path = path.to_string_lossy().to_string();
+        let line_error = dotenvy::Error::LineParse("PORT 80".to_string(), 4);
+        let snippet = FileLine::capture_error(&path, &line_error);
+        let error = EnvSettingsError::File(path.to_owned(), line_error, snippet);
+        let rendered = render(&error);
+        assert!(rendered.contains(&format!("Begin snippet for {path} starting at line 2")));
+        assert!(rendered.contains("snippet line 2: PORT 80"));
+        assert!(!rendered.contains("localhost"));
+    }
+
+    #[test]
+    fn test_diagnostic_multiple() {
+        let error = EnvSettingsError::Multiple(vec![
+            EnvSettingsError::Empty("PORT".to_string()),
+            EnvSettingsError::NotUnicode("HOST".to_string()),
+        ]);
+        let related = error
+            .related()
+            .expect("Related diagnostics not found!")
+            .map(|related| related.to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(
+            related,
+            vec![
+                "Environment variable named `PORT` is empty",
+                "Environment variable named `HOST` is not valid unicode"
+            ]
+        );
+        assert!(render(&error).contains("diagnostic help: set a value to `PORT` or unset it"));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::units;
+
+use std::time;
+
+/// The error that may occurs while parsing a human-readable duration
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ParseDurationError {
+    /// Error raised when the value is empty
+    #[error("empty duration")]
+    Empty,
+
+    /// Error raised when a number is not valid
+    #[error("invalid number `{0}`")]
+    InvalidNumber(String),
+
+    /// Error raised when a number is not followed by a unit
+    #[error("missing unit after `{0}`")]
+    MissingUnit(String),
+
+    /// Error raised when a unit is not supported
+    #[error("unknown unit `{0}`, expected one of `ns`, `us`, `ms`, `s`, `m`, `h` or `d`")]
+    UnknownUnit(String),
+
+    /// Error raised when the duration is too big
+    #[error("duration too big")]
+    Overflow,
+}
+
+/// Get the number of nanoseconds of a duration unit
+fn get_unit_nanos(unit: &str) -> Option<u128> {
+    let nanos = match unit {
+        "ns" => 1,
+        "us" | "µs" => 1_000,
+        "ms" => 1_000_000,
+        "s" => 1_000_000_000,
+        "m" => 60 * 1_000_000_000,
+        "h" => 60 * 60 * 1_000_000_000,
+        "d" => 24 * 60 * 60 * 1_000_000_000,
+        _ => return None,
+    };
+    Some(nanos)
+}
+
+/// Parse a human-readable duration, like `30s`, `5m`, `1h30m` or `1.5d`
+pub fn parse_duration(value: &str) -> Result<time::Duration, ParseDurationError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    if value.chars().all(|char| char == '0') {
+        return Ok(time::Duration::ZERO);
+    }
+    let mut total_nanos: u128 = 0;
+    let mut remaining = value;
+    while !remaining.is_empty() {
+        let (number, rest) = units::split_number(remaining);
+        let unit_length = rest
+            .find(|char: char| char.is_ascii_digit() || char == '.')
+            .unwrap_or(rest.len());
+        let (unit, rest) = rest.split_at(unit_length);
+        if number.is_empty() {
+            return Err(ParseDurationError::InvalidNumber(unit.to_string()));
+        }
+        if unit.is_empty() {
+            return Err(ParseDurationError::MissingUnit(number.to_string()));
+        }
+        let unit_nanos =
+            get_unit_nanos(unit).ok_or(ParseDurationError::UnknownUnit(unit.to_string()))?;
+        let nanos = units::scale_number(number, unit_nanos)
+            .ok_or(ParseDurationError::InvalidNumber(number.to_string()))?;
+        total_nanos = total_nanos
+            .checked_add(nanos)
+            .ok_or(ParseDurationError::Overflow)?;
+        remaining = rest;
+    }
+    let seconds =
+        u64::try_from(total_nanos / 1_000_000_000).map_err(|_| ParseDurationError::Overflow)?;
+    let nanos = (total_nanos % 1_000_000_000) as u32;
+    Ok(time::Duration::new(seconds, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("30s", Ok(time::Duration::from_secs(30)))]
+    #[case("5m", Ok(time::Duration::from_secs(300)))]
+    #[case("1h30m", Ok(time::Duration::from_secs(5400)))]
+    #[case("1.5d", Ok(time::Duration::from_secs(129600)))]
+    #[case("250ms", Ok(time::Duration::from_millis(250)))]
+    #[case("10us", Ok(time::Duration::from_micros(10)))]
+    #[case("10µs", Ok(time::Duration::from_micros(10)))]
+    #[case("7ns", Ok(time::Duration::from_nanos(7)))]
+    #[case(" 1m1s ", Ok(time::Duration::from_secs(61)))]
+    #[case("0", Ok(time::Duration::ZERO))]
+    #[case("", Err(ParseDurationError::Empty))]
+    #[case("30", Err(ParseDurationError::MissingUnit("30".to_string())))]
+    #[case("1h30", Err(ParseDurationError::MissingUnit("30".to_string())))]
+    #[case("s", Err(ParseDurationError::InvalidNumber("s".to_string())))]
+    #[case("1.2.3s", Err(ParseDurationError::InvalidNumber("1.2.3".to_string())))]
+    #[case("3w", Err(ParseDurationError::UnknownUnit("w".to_string())))]
+    #[case("1 h", Err(ParseDurationError::UnknownUnit(" h".to_string())))]
+    #[case("99999999999999999999999d", Err(ParseDurationError::Overflow))]
+    fn test_parse_duration(
+        #[case] value: &str,
+        #[case] expected_result: Result<time::Duration, ParseDurationError>,
+    ) {
+        assert_eq!(parse_duration(value), expected_result);
+    }
+}
@@ -0,0 +1,117 @@
+use base64::{Engine, engine};
+
+/// The error that may occurs while decoding binary values
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ParseBytesError {
+    /// Error raised when the value is not valid base64
+    #[error("invalid base64 value")]
+    InvalidBase64,
+
+    /// Error raised when the value is not valid hex
+    #[error("invalid hex value")]
+    InvalidHex,
+
+    /// Error raised when the number of decoded bytes is not the expected one
+    #[error("expected {expected} bytes, got {actual}")]
+    Length {
+        /// The expected number of bytes
+        expected: usize,
+
+        /// The actual number of bytes
+        actual: usize,
+    },
+}
+
+/// A binary type that can be built from decoded bytes
+pub trait FromBytes: Sized {
+    /// Build the value from the decoded bytes
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self, ParseBytesError>;
+}
+
+impl FromBytes for Vec<u8> {
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self, ParseBytesError> {
+        Ok(bytes)
+    }
+}
+
+impl<const N: usize> FromBytes for [u8; N] {
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self, ParseBytesError> {
+        let actual = bytes.len();
+        bytes.try_into().map_err(|_| ParseBytesError::Length {
+            expected: N,
+            actual,
+        })
+    }
+}
+
+const BASE64_CONFIG: engine::GeneralPurposeConfig = engine::GeneralPurposeConfig::new()
+    .with_decode_padding_mode(engine::DecodePaddingMode::Indifferent);
+
+/// Decode a base64 value, using either the standard or the URL safe alphabet, with or without padding
+pub fn decode_base64<T: FromBytes>(value: &str) -> Result<T, ParseBytesError> {
+    let standard = engine::GeneralPurpose::new(&base64::alphabet::STANDARD, BASE64_CONFIG);
+    let url_safe = engine::GeneralPurpose::new(&base64::alphabet::URL_SAFE, BASE64_CONFIG);
+    let bytes = standard
+        .decode(value)
+        .or_else(|_| url_safe.decode(value))
+        .map_err(|_| ParseBytesError::InvalidBase64)?;
+    T::from_bytes(bytes)
+}
+
+/// Decode a hex value, case insensitive
+pub fn decode_hex<T: FromBytes>(value: &str) -> Result<T, ParseBytesError> {
+    let bytes = hex::decode(value).map_err(|_| ParseBytesError::InvalidHex)?;
+    T::from_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("bG9yZW0=", Ok(b"lorem".to_vec()))]
+    #[case("bG9yZW0", Ok(b"lorem".to_vec()))]
+    #[case("-_8", Ok(vec![0xfb, 0xff]))]
+    #[case("+/8=", Ok(vec![0xfb, 0xff]))]
+    #[case("not base64!", Err(ParseBytesError::InvalidBase64))]
+    fn test_decode_base64(
+        #[case] value: &str,
+        #[case] expected_result: Result<Vec<u8>, ParseBytesError>,
+    ) {
+        assert_eq!(decode_base64(value), expected_result);
+    }
+
+    #[rstest]
+    #[case("deadBEEF", Ok(vec![0xde, 0xad, 0xbe, 0xef]))]
+    #[case("", Ok(vec![]))]
+    #[case("dead bee", Err(ParseBytesError::InvalidHex))]
+    #[case("abc", Err(ParseBytesError::InvalidHex))]
+    fn test_decode_hex(
+        #[case] value: &str,
+        #[case] expected_result: Result<Vec<u8>, ParseBytesError>,
+    ) {
+        assert_eq!(decode_hex(value), expected_result);
+    }
+
+    #[rstest]
+    #[case("deadbeef", Ok([0xde, 0xad, 0xbe, 0xef]))]
+    #[case("deadbe", Err(ParseBytesError::Length { expected: 4, actual: 3 }))]
+    fn test_decode_array(
+        #[case] value: &str,
+        #[case] expected_result: Result<[u8; 4], ParseBytesError>,
+    ) {
+        assert_eq!(decode_hex(value), expected_result);
+    }
+
+    #[rstest]
+    #[case(ParseBytesError::Length { expected: 32, actual: 31 }, "expected 32 bytes, got 31")]
+    fn test_display_parse_bytes_error(
+        #[case] error: ParseBytesError,
+        #[case] expected_result: &str,
+    ) {
+        assert_eq!(error.to_string(), expected_result);
+    }
+}
@@ -0,0 +1,218 @@
+use std::{fmt, path, sync};
+
+/// Where the value of a field comes from
+#[derive(Clone, Debug, PartialEq)]
+pub enum Origin {
+    /// The argument passed to `new` or `from_env`
+    Argument,
+
+    /// The environment variable with the given name
+    Environment(String),
+
+    /// The environment variable with the given name, declared in a file at the given line
+    File {
+        /// The name of the variable
+        variable: String,
+        /// The absolute path of the file
+        path: sync::Arc<path::PathBuf>,
+        /// The line of the declaration, starting from 1
+        line: usize,
+    },
+
+    /// The `default` parameter
+    Default,
+
+    /// Not set, for optional fields
+    Unset,
+}
+
+impl Origin {
+    /// Get the name of the environment variable, if the value comes from one
+    pub fn variable(&self) -> Option<&str> {
+        match self {
+            Origin::Environment(variable) | Origin::File { variable, .. } => Some(variable),
+            _ => None,
+        }
+    }
+
+    /// Get a short description of the source of the value
+    pub fn source(&self) -> String {
+        match self {
+            Origin::Argument => "argument".to_string(),
+            Origin::Environment(_) => "environment".to_string(),
+            Origin::File { path, line, .. } => format!("{}:{line}", path.display()),
+            Origin::Default => "default".to_string(),
+            Origin::Unset => "unset".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Argument => write!(f, "argument"),
+            Origin::Environment(variable) => write!(f, "environment variable `{variable}`"),
+            Origin::File {
+                variable,
+                path,
+                line,
+            } => write!(
+                f,
+                "environment variable `{variable}` at `{}:{line}`",
+                path.display()
+            ),
+            Origin::Default => write!(f, "default value"),
+            Origin::Unset => write!(f, "unset value"),
+        }
+    }
+}
+
+/// The resolution of a field
+#[derive(Clone, Debug, PartialEq)]
+pub struct Resolution {
+    /// The name of the field
+    pub field: &'static str,
+
+    /// Where the value comes from
+    pub origin: Origin,
+
+    /// The value to parse, redacted if the field is secret. It is not available for the arguments
+    /// and the defaults that are not strings or literals
+    pub value: Option<String>,
+}
+
+/// The report of where the values of the fields come from
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Explanation {
+    resolutions: Vec<Resolution>,
+}
+
+impl Explanation {
+    /// Create an empty report
+    pub const fn new() -> Self {
+        Explanation {
+            resolutions: Vec::new(),
+        }
+    }
+
+    /// Get the resolutions of the fields, in declaration order
+    pub fn resolutions(&self) -> &[Resolution] {
+        &self.resolutions
+    }
+
+    /// Record the resolution of a field, replacing the previous one of the same field
+    pub fn record(&mut self, field: &'static str, origin: Origin, value: Option<String>) {
+        let resolution = Resolution {
+            field,
+            origin,
+            value,
+        };
+        match self
+            .resolutions
+            .iter_mut()
+            .find(|resolution| resolution.field == field)
+        {
+            Some(previous_resolution) => *previous_resolution = resolution,
+            None => self.resolutions.push(resolution),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    /// Render the report as a table of field, variable, source and value
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = ["field", "variable", "source", "value"].map(str::to_string);
+        let rows = self.resolutions.iter().map(|resolution| {
+            [
+                resolution.field.to_string(),
+                resolution.origin.variable().unwrap_or("-").to_string(),
+                resolution.origin.source(),
+                resolution.value.as_deref().unwrap_or("-").to_string(),
+            ]
+        });
+        let rows = [header].into_iter().chain(rows).collect::<Vec<_>>();
+        let mut widths = [0; 4];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for (index, row) in rows.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<String>>()
+                .join("  ");
+            write!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Origin::Argument, None, "argument", "argument")]
+    #[case(
+        Origin::Environment("PORT".to_string()),
+        Some("PORT"),
+        "environment",
+        "environment variable `PORT`"
+    )]
+    #[case(
+        Origin::File { variable: "PORT".to_string(), path: path::PathBuf::from("/app/.env").into(), line: 3 },
+        Some("PORT"),
+        "/app/.env:3",
+        "environment variable `PORT` at `/app/.env:3`"
+    )]
+    #[case(Origin::Default, None, "default", "default value")]
+    #[case(Origin::Unset, None, "unset", "unset value")]
+    fn test_origin(
+        #[case] origin: Origin,
+        #[case] expected_variable: Option<&str>,
+        #[case] expected_source: &str,
+        #[case] expected_result: &str,
+    ) {
+        assert_eq!(origin.variable(), expected_variable);
+        assert_eq!(origin.source(), expected_source);
+        assert_eq!(origin.to_string(), expected_result);
+    }
+
+    #[test]
+    fn test_explanation() {
+        let mut expected_result = Explanation::new();
+        expected_result.record(
+            "port",
+            Origin::Environment("PORT".to_string()),
+            Some("".to_string()),
+        );
+        expected_result.record("port", Origin::Default, Some("8080".to_string()));
+        expected_result.record("password", Origin::Argument, None);
+        expected_result.record(
+            "host",
+            Origin::File {
+                variable: "APP_HOST".to_string(),
+                path: path::PathBuf::from("/app/.env").into(),
+                line: 12,
+            },
+            Some("localhost".to_string()),
+        );
+        assert_eq!(expected_result.resolutions().len(), 3);
+        assert_eq!(
+            expected_result.to_string(),
+            "field     variable  source        value\n\
+             port      -         default       8080\n\
+             password  -         argument      -\n\
+             host      APP_HOST  /app/.env:12  localhost"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Origin, REDACTED};
+
+use std::{fs, path};
+
+/// A line of an environment variables file, captured when an error is raised so that it can be shown later.
+///
+/// Only the line is kept, so that the other variables of the file, which may be secrets, are not leaked
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileLine {
+    /// The path of the file
+    pub path: String,
+
+    /// The number of the line, starting from 1
+    pub number: usize,
+
+    /// The content of the line
+    pub text: String,
+}
+
+impl FileLine {
+    /// Read a line of a file
+    pub fn read(path: &path::Path, number: usize) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let text = content
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .nth(number.checked_sub(1)?)?;
+        Some(FileLine {
+            path: path.display().to_string(),
+            number,
+            text: text.to_string(),
+        })
+    }
+
+    /// Capture the line of a file declaring a value that can not be converted.
+    ///
+    /// Redacted values and values no longer declared in the file are not captured
+    pub fn capture(origin: &Origin, value: &str) -> Option<Box<Self>> {
+        let Origin::File { path, line, .. } = origin else {
+            return None;
+        };
+        if value.is_empty() || value.contains(REDACTED) {
+            return None;
+        }
+        let file_line = Self::read(path, *line)?;
+        file_line.find(value)?;
+        Some(Box::new(file_line))
+    }
+
+    /// Capture the line of a file that can not be parsed
+    pub fn capture_error(path: &str, error: &dotenvy::Error) -> Option<Box<Self>> {
+        let dotenvy::Error::LineParse(text, _) = error else {
+            return None;
+        };
+        let first_line = text.lines().next()?;
+        let content = fs::read_to_string(path).ok()?;
+        let number = content
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .position(|line| line == first_line)?
+            + 1;
+        Some(Box::new(FileLine {
+            path: path.to_string(),
+            number,
+            text: first_line.to_string(),
+        }))
+    }
+
+    /// Find the offset of a value in the line, after the `=` of the declaration
+    pub fn find(&self, value: &str) -> Option<usize> {
+        let start = self.text.find('=')? + 1;
+        Some(start + self.text[start..].find(value)?)
+    }
+}
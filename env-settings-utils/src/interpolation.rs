@@ -0,0 +1,146 @@
+use crate::{EnvSettingsError, EnvSettingsResult, EnvVariables, to_str};
+
+/// Find the index of the `}` closing a reference whose content starts at the beginning of `value`
+fn find_closing_brace(value: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut chars = value.char_indices().peekable();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '$' if chars.peek().map(|(_, char)| *char) == Some('{') => {
+                chars.next();
+                depth += 1;
+            }
+            '}' if depth == 0 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Expand the references in a value, keeping track of the variables being expanded
+fn expand(
+    value: &str,
+    env_variables: &EnvVariables,
+    stack: &mut Vec<String>,
+) -> EnvSettingsResult<String> {
+    let mut expanded_value = String::with_capacity(value.len());
+    let mut remaining = value;
+    while let Some(start) = remaining.find('$') {
+        expanded_value.push_str(&remaining[..start]);
+        let rest = &remaining[start..];
+        if let Some(rest) = rest.strip_prefix("$${") {
+            // `$${` is an escaped `${`
+            expanded_value.push_str("${");
+            remaining = rest;
+        } else if let Some(reference) = rest.strip_prefix("${")
+            && let Some(end) = find_closing_brace(reference)
+        {
+            let (name, fallback) = match reference[..end].split_once(":-") {
+                Some((name, fallback)) => (name, Some(fallback)),
+                None => (&reference[..end], None),
+            };
+            let key = env_variables.matching().normalize(name);
+            // like in shells, the fallback is used also when the variable is empty
+            let referenced_value = env_variables
+                .get(&key)?
+                .filter(|referenced_value| fallback.is_none() || !referenced_value.is_empty());
+            match (referenced_value, fallback) {
+                (Some(referenced_value), _) => {
+                    if stack.contains(&key) {
+                        let mut cycle = stack.to_owned();
+                        cycle.push(key);
+                        return Err(EnvSettingsError::InterpolationCycle(cycle));
+                    }
+                    let referenced_value = to_str(referenced_value, name)?;
+                    stack.push(key);
+                    let referenced_value = expand(referenced_value, env_variables, stack)?;
+                    stack.pop();
+                    expanded_value.push_str(&referenced_value);
+                }
+                (None, Some(fallback)) => {
+                    let fallback = expand(fallback, env_variables, stack)?;
+                    expanded_value.push_str(&fallback);
+                }
+                (None, None) => {
+                    return Err(EnvSettingsError::InterpolationNotExists(name.to_string()));
+                }
+            }
+            remaining = &reference[end + 1..];
+        } else {
+            expanded_value.push('$');
+            remaining = &rest[1..];
+        }
+    }
+    expanded_value.push_str(remaining);
+    Ok(expanded_value)
+}
+
+/// Expand the `${VARIABLE}` and `${VARIABLE:-fallback}` references in a value using the environment variables.
+///
+/// The fallback is used when the referenced variable is not set or empty, and `$${` is an escaped `${`
+pub fn interpolate(value: &str, env_variables: &EnvVariables) -> EnvSettingsResult<String> {
+    expand(value, env_variables, &mut Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use crate::KeyMatching;
+    use rstest::rstest;
+    use std::{collections::HashMap, ffi};
+
+    #[rstest]
+    #[case("plain", HashMap::from([]), KeyMatching::Exact, Ok("plain"))]
+    #[case("${USER}", HashMap::from([("USER", "lorem")]), KeyMatching::Exact, Ok("lorem"))]
+    #[case(
+        "postgres://${DB_USER}@${DB_HOST}/app",
+        HashMap::from([("DB_USER", "lorem"), ("DB_HOST", "localhost")]),
+        KeyMatching::Exact,
+        Ok("postgres://lorem@localhost/app")
+    )]
+    #[case("${DB_USER}", HashMap::from([("db_user", "lorem")]), KeyMatching::CaseInsensitive, Ok("lorem"))]
+    #[case("${db.user}", HashMap::from([("DB_USER", "lorem")]), KeyMatching::Normalized, Ok("lorem"))]
+    #[case(
+        "${DB_USER}",
+        HashMap::from([("db_user", "lorem"), ("DB_USER", "ipsum")]),
+        KeyMatching::CaseInsensitive,
+        Err(EnvSettingsError::Ambiguous("db_user".to_string(), vec!["DB_USER".to_string(), "db_user".to_string()]))
+    )]
+    #[case("${HOME:-/root}/.cache", HashMap::from([]), KeyMatching::Exact, Ok("/root/.cache"))]
+    #[case("${HOME:-/root}/.cache", HashMap::from([("HOME", "")]), KeyMatching::Exact, Ok("/root/.cache"))]
+    #[case("${HOME:-/root}/.cache", HashMap::from([("HOME", "/home")]), KeyMatching::Exact, Ok("/home/.cache"))]
+    #[case("${A:-${B}}", HashMap::from([("B", "lorem")]), KeyMatching::Exact, Ok("lorem"))]
+    #[case("${A}", HashMap::from([("A", "${B}"), ("B", "lorem")]), KeyMatching::Exact, Ok("lorem"))]
+    #[case("$${A} costs $5", HashMap::from([]), KeyMatching::Exact, Ok("${A} costs $5"))]
+    #[case("${A", HashMap::from([]), KeyMatching::Exact, Ok("${A"))]
+    #[case(
+        "${A}",
+        HashMap::from([]),
+        KeyMatching::Exact,
+        Err(EnvSettingsError::InterpolationNotExists("A".to_string()))
+    )]
+    #[case(
+        "${A}",
+        HashMap::from([("A", "${B}"), ("B", "${A}")]),
+        KeyMatching::Exact,
+        Err(EnvSettingsError::InterpolationCycle(vec!["A".to_string(), "B".to_string(), "A".to_string()]))
+    )]
+    fn test_interpolate(
+        #[case] value: &str,
+        #[case] env_variables: HashMap<&str, &str>,
+        #[case] matching: KeyMatching,
+        #[case] expected_result: Result<&str, EnvSettingsError>,
+    ) {
+        let env_variables = EnvVariables::new(
+            env_variables
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), ffi::OsString::from(value))),
+            matching,
+        );
+        let actual_result = interpolate(value, &env_variables);
+        assert_eq!(actual_result, expected_result.map(str::to_string));
+    }
+}
@@ -6,7 +6,66 @@
 
 //! # **Env Settinsg Utils**
 
-use std::{collections, env};
+use std::{collections, env, ffi, fmt, fs, path};
+
+#[cfg(feature = "byte-size")]
+mod byte_size;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod date_time;
+mod delimited;
+#[cfg(feature = "miette")]
+mod diagnostic;
+#[cfg(feature = "dsn")]
+mod dsn;
+#[cfg(feature = "duration")]
+mod duration;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod explanation;
+mod file_line;
+mod interpolation;
+mod optional;
+mod os_string;
+mod path_buf;
+#[cfg(feature = "secret")]
+mod secret;
+mod source;
+mod suggestion;
+#[cfg(any(feature = "byte-size", feature = "duration"))]
+mod units;
+mod variant;
+mod warning;
+
+#[cfg(feature = "byte-size")]
+pub use byte_size::{ByteSize, ParseByteSizeError};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use date_time::ParseDateTimeError;
+#[cfg(feature = "chrono")]
+pub use date_time::{FromChrono, parse_chrono};
+#[cfg(feature = "time")]
+pub use date_time::{FromTime, parse_time};
+pub use delimited::{ParseDelimitedError, parse_array, parse_delimited, split_delimited};
+#[cfg(feature = "dsn")]
+pub use dsn::{Dsn, ParseDsnError, parse_dsn};
+#[cfg(feature = "duration")]
+pub use duration::{ParseDurationError, parse_duration};
+#[cfg(feature = "encoding")]
+pub use encoding::{FromBytes, ParseBytesError, decode_base64, decode_hex};
+pub use explanation::{Explanation, Origin, Resolution};
+pub use file_line::FileLine;
+pub use interpolation::interpolate;
+pub use optional::Optional;
+pub use os_string::parse_os_string;
+pub use path_buf::parse_path;
+#[cfg(feature = "secret")]
+pub use secret::Secret;
+pub use source::{
+    ConvertSourceRef, DisplaySource, DisplaySourceKind, ErrorSource, ErrorSourceKind,
+    FallbackSource, FallbackSourceKind, INVALID_VALUE,
+};
+pub use suggestion::suggest_env_variable;
+pub use variant::{ParseEnumError, normalize_variant};
+pub use warning::{EnvSettingsWarning, WarningHook, set_warning_hook, warn};
 
 /// The result type provided by `EnvSettings`
 pub type EnvSettingsResult<T> = Result<T, EnvSettingsError>;
@@ -14,55 +73,419 @@ pub type EnvSettingsResult<T> = Result<T, EnvSettingsError>;
 /// The error that may occurs during `EnvSettings` resolution
 #[derive(Debug, thiserror::Error)]
 pub enum EnvSettingsError {
+    /// Error raised when several environment variables match the same name
+    #[error(
+        "Environment variable named `{0}` is ambiguous, it matches {variables}",
+        variables = .1.iter().map(|variable| format!("`{variable}`")).collect::<Vec<_>>().join(", ")
+    )]
+    Ambiguous(String, Vec<String>),
+
     /// Error raised when a convertion fails
-    #[error("Unable to convert the field `{0}`: `{1}` to `{2}`")]
-    Convert(&'static str, String, &'static str),
+    #[error("Unable to convert the field `{field}`: `{value}` from the {origin} to `{type_name}`")]
+    Convert {
+        /// The name of the field
+        field: &'static str,
+        /// The value to convert, redacted if the field is secret
+        value: String,
+        /// Where the value comes from
+        origin: Origin,
+        /// The name of the type of the field
+        type_name: &'static str,
+        /// The error raised by the parsing, redacted if the field is secret
+        source: Box<dyn std::error::Error + Send + Sync>,
+        /// The line of the environment variables file declaring the value, if it comes from a file and it is not redacted
+        snippet: Option<Box<FileLine>>,
+    },
+
+    /// Error raised when an environment variable is set but empty
+    #[error("Environment variable named `{0}` is empty")]
+    Empty(String),
 
-    /// Error raised when environment variables resolution from a file fails
+    /// Error raised when environment variables resolution from a file fails, along with the line that can not be parsed
     #[error("Error occurs while reading `{0}` as environment variable file: {1}")]
-    File(String, dotenvy::Error),
+    File(String, dotenvy::Error, Option<Box<FileLine>>),
+
+    /// Error raised when environment variables reference each other while interpolating
+    #[error(
+        "Cyclic interpolation of environment variables: {}",
+        .0.iter().map(|variable| format!("`{variable}`")).collect::<Vec<_>>().join(" -> ")
+    )]
+    InterpolationCycle(Vec<String>),
+
+    /// Error raised when an environment variable referenced while interpolating not exists
+    #[error("Environment variable named `{0}` referenced by an interpolation not found")]
+    InterpolationNotExists(String),
+
+    /// Error raised when the resolution of several fields fails
+    #[error(
+        "{} errors occur while resolving the environment variables:\n{}",
+        .0.len(),
+        .0.iter().map(|error| format!("  - {}", error.display_chain())).collect::<Vec<_>>().join("\n")
+    )]
+    Multiple(Vec<EnvSettingsError>),
+
+    /// Error raised when none of the environment variables of a field exists
+    #[error(
+        "Environment variable for the field `{field}` not found: looked up {names}{matching} in {searched}{hint}",
+        names = .variables.iter().map(|variable| format!("`{variable}`")).collect::<Vec<_>>().join(", "),
+        matching = match .matching {
+            KeyMatching::Exact => "",
+            KeyMatching::CaseInsensitive => " ignoring case",
+            KeyMatching::Normalized => " ignoring case and separators",
+        },
+        searched = .sources.iter().map(EnvSource::to_string).collect::<Vec<_>>().join(" and "),
+        hint = .suggestion.as_ref().map(|suggestion| format!(", did you mean `{suggestion}`?")).unwrap_or_default()
+    )]
+    NotExists {
+        /// The name of the field
+        field: &'static str,
+        /// The names of the variables looked up in priority order, normalized according to the matching
+        variables: Vec<String>,
+        /// How the names are matched
+        matching: KeyMatching,
+        /// The sources of the variables consulted
+        sources: Vec<EnvSource>,
+        /// The most similar variable set, if any
+        suggestion: Option<String>,
+    },
 
-    /// Error raised when an environment variable not exists
-    #[error("Environment variable named `{0}` not found")]
-    NotExists(&'static str),
+    /// Error raised when an environment variable is not valid unicode
+    #[error("Environment variable named `{0}` is not valid unicode")]
+    NotUnicode(String),
 }
 
+impl EnvSettingsError {
+    /// Combine the errors of the fields, returning the error as it is if there is just one
+    pub fn from_errors(mut errors: Vec<EnvSettingsError>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Self::Multiple(errors)
+        }
+    }
+
+    /// Format the error followed by its sources, since the sources of the combined errors are not chained
+    fn display_chain(&self) -> String {
+        let mut message = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(error) = source {
+            message.push_str(&format!(": {error}"));
+            source = error.source();
+        }
+        message
+    }
+}
+
+/// The captured lines are not compared, since they only depend on the content of the files
 impl PartialEq for EnvSettingsError {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            (Self::Convert(l0, l1, l2), Self::Convert(r0, r1, r2)) => {
-                l0 == r0 && l1 == r1 && l2 == r2
-            }
-            (Self::File(l0, l1), Self::File(r0, r1)) => {
+            (Self::Ambiguous(l0, l1), Self::Ambiguous(r0, r1)) => l0 == r0 && l1 == r1,
+            (
+                Self::Convert {
+                    field: l0,
+                    value: l1,
+                    origin: l2,
+                    type_name: l3,
+                    source: l4,
+                    ..
+                },
+                Self::Convert {
+                    field: r0,
+                    value: r1,
+                    origin: r2,
+                    type_name: r3,
+                    source: r4,
+                    ..
+                },
+            ) => l0 == r0 && l1 == r1 && l2 == r2 && l3 == r3 && l4.to_string() == r4.to_string(),
+            (Self::Empty(l0), Self::Empty(r0)) => l0 == r0,
+            (Self::File(l0, l1, _), Self::File(r0, r1, _)) => {
                 l0 == r0 && l1.to_string() == r1.to_string()
             }
-            (Self::NotExists(l0), Self::NotExists(r0)) => l0 == r0,
+            (Self::InterpolationCycle(l0), Self::InterpolationCycle(r0)) => l0 == r0,
+            (Self::InterpolationNotExists(l0), Self::InterpolationNotExists(r0)) => l0 == r0,
+            (Self::Multiple(l0), Self::Multiple(r0)) => l0 == r0,
+            (
+                Self::NotExists {
+                    field: l0,
+                    variables: l1,
+                    matching: l2,
+                    sources: l3,
+                    suggestion: l4,
+                },
+                Self::NotExists {
+                    field: r0,
+                    variables: r1,
+                    matching: r2,
+                    sources: r3,
+                    suggestion: r4,
+                },
+            ) => l0 == r0 && l1 == r1 && l2 == r2 && l3 == r3 && l4 == r4,
+            (Self::NotUnicode(l0), Self::NotUnicode(r0)) => l0 == r0,
             _ => false,
         }
     }
 }
 
-/// Get the environment variables
-pub fn get_env_variables(case_insensitive: bool) -> collections::HashMap<String, String> {
-    let env_variables = env::vars();
-    if case_insensitive {
+/// How the names of the environment variables are matched
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum KeyMatching {
+    /// The names must be equal
+    #[default]
+    Exact,
+
+    /// The names are compared ignoring case
+    CaseInsensitive,
+
+    /// The names are compared ignoring case and treating `-`, `.` and `_` as equivalent
+    Normalized,
+}
+
+impl KeyMatching {
+    /// Normalize a name, so that the matching names are equal
+    pub fn normalize(self, key: &str) -> String {
+        match self {
+            KeyMatching::Exact => key.to_string(),
+            KeyMatching::CaseInsensitive => key.to_lowercase(),
+            KeyMatching::Normalized => key
+                .chars()
+                .map(|char| if matches!(char, '-' | '.') { '_' } else { char })
+                .flat_map(char::to_lowercase)
+                .collect(),
+        }
+    }
+}
+
+/// The environment variables, whose names are normalized according to the matching
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvVariables {
+    /// How the names are matched
+    matching: KeyMatching,
+
+    /// The values of the variables, by normalized name
+    variables: collections::HashMap<String, ffi::OsString>,
+
+    /// The original names of the variables sharing the same normalized name with different values
+    ambiguities: collections::HashMap<String, Vec<String>>,
+
+    /// The original names of the variables, sorted
+    names: Vec<String>,
+}
+
+impl EnvVariables {
+    /// Collect the variables, normalizing their names
+    pub fn new(
+        variables: impl IntoIterator<Item = (String, ffi::OsString)>,
+        matching: KeyMatching,
+    ) -> Self {
+        let mut originals = collections::HashMap::<String, Vec<(String, ffi::OsString)>>::new();
+        let mut names = Vec::new();
+        for (key, value) in variables {
+            names.push(key.to_owned());
+            originals
+                .entry(matching.normalize(&key))
+                .or_default()
+                .push((key, value));
+        }
+        names.sort();
+        let mut env_variables = EnvVariables {
+            matching,
+            names,
+            ..Default::default()
+        };
+        for (key, mut originals) in originals {
+            // variables sharing the same value are not ambiguous
+            if originals.iter().all(|(_, value)| *value == originals[0].1) {
+                let (_, value) = originals.swap_remove(0);
+                env_variables.variables.insert(key, value);
+            } else {
+                let mut names = originals
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<String>>();
+                names.sort();
+                env_variables.ambiguities.insert(key, names);
+            }
+        }
         env_variables
-            .map(|(key, value)| (key.to_lowercase(), value))
-            .collect()
-    } else {
-        env_variables.collect()
     }
+
+    /// Get how the names are matched
+    pub fn matching(&self) -> KeyMatching {
+        self.matching
+    }
+
+    /// Get the original names of the variables, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Get the value of a variable by its normalized name.
+    ///
+    /// If several variables with different values match the name, an error is returned
+    pub fn get(&self, key: &str) -> EnvSettingsResult<Option<&ffi::OsString>> {
+        match self.ambiguities.get(key) {
+            Some(names) => Err(EnvSettingsError::Ambiguous(
+                key.to_string(),
+                names.to_owned(),
+            )),
+            None => Ok(self.variables.get(key)),
+        }
+    }
+}
+
+/// Get the environment variables.
+///
+/// Values are kept as `OsString`, so that non unicode values do not panic,
+/// while variables whose name is not valid unicode are ignored since they cannot be matched
+pub fn get_env_variables(matching: KeyMatching) -> EnvVariables {
+    let env_variables =
+        env::vars_os().filter_map(|(key, value)| key.into_string().ok().map(|key| (key, value)));
+    EnvVariables::new(env_variables, matching)
+}
+
+/// Get the first environment variable set among the variable and its aliases, in priority order.
+///
+/// The name of the variable found is returned along with its value
+pub fn find_env_variable<'a, 'b>(
+    env_variables: &'a EnvVariables,
+    variables: &[&'b str],
+) -> EnvSettingsResult<Option<(&'b str, &'a ffi::OsString)>> {
+    for variable in variables {
+        if let Some(value) = env_variables.get(variable)? {
+            return Ok(Some((*variable, value)));
+        }
+    }
+    Ok(None)
+}
+
+/// Get the value of an environment variable as `str`
+pub fn to_str<'a>(value: &'a ffi::OsStr, variable: &str) -> EnvSettingsResult<&'a str> {
+    value
+        .to_str()
+        .ok_or(EnvSettingsError::NotUnicode(variable.to_string()))
 }
 
-/// Load the environment variables file path
-pub fn load_env_file_path(file_path: &str) -> EnvSettingsResult<()> {
-    if let Err(err) = dotenvy::from_path(file_path) {
-        Err(EnvSettingsError::File(file_path.to_string(), err))
+/// The placeholder of the redacted values
+pub const REDACTED: &str = "***";
+
+/// Redact every occurrence of a secret value in an error message
+pub fn redact(message: &str, value: &str) -> String {
+    if value.is_empty() {
+        message.to_string()
     } else {
-        Ok(())
+        message.replace(value, REDACTED)
     }
 }
 
+/// A source of environment variables consulted while resolving a field
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnvSource {
+    /// The environment of the process
+    Environment,
+
+    /// The environment variables file with the given absolute path
+    File(path::PathBuf),
+}
+
+impl fmt::Display for EnvSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvSource::Environment => write!(f, "the process environment"),
+            EnvSource::File(path) => write!(f, "`{}`", path.display()),
+        }
+    }
+}
+
+/// The environment variables loaded from a file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EnvFile {
+    /// The absolute path of the file
+    pub path: path::PathBuf,
+
+    /// The variables declared in the file
+    pub variables: collections::HashMap<String, String>,
+
+    /// The lines of the declarations of the variables, starting from 1
+    pub lines: collections::HashMap<String, usize>,
+}
+
+impl EnvFile {
+    /// Get the directory containing the file
+    pub fn dir(&self) -> &path::Path {
+        self.path.parent().unwrap_or(path::Path::new(""))
+    }
+
+    /// Whether the value of an environment variable was supplied by the file
+    pub fn supplies(
+        &self,
+        key: &str,
+        value: impl AsRef<ffi::OsStr>,
+        matching: KeyMatching,
+    ) -> bool {
+        self.origin(key, value, matching).is_some()
+    }
+
+    /// Get the origin of the value of an environment variable, if it was supplied by the file
+    pub fn origin(
+        &self,
+        key: &str,
+        value: impl AsRef<ffi::OsStr>,
+        matching: KeyMatching,
+    ) -> Option<Origin> {
+        let value = value.as_ref();
+        self.variables
+            .iter()
+            .find(|(file_key, file_value)| {
+                matching.normalize(file_key) == key && ffi::OsStr::new(file_value) == value
+            })
+            .map(|(file_key, _)| Origin::File {
+                variable: file_key.to_owned(),
+                path: self.path.to_owned().into(),
+                line: self.lines.get(file_key).copied().unwrap_or_default(),
+            })
+    }
+}
+
+/// Load the environment variables file path.
+///
+/// Variables already set in the environment are preserved
+pub fn load_env_file_path(file_path: &str) -> EnvSettingsResult<EnvFile> {
+    let into_error = |err| {
+        let snippet = FileLine::capture_error(file_path, &err);
+        EnvSettingsError::File(file_path.to_string(), err, snippet)
+    };
+    dotenvy::from_path(file_path).map_err(into_error)?;
+    let mut variables = collections::HashMap::new();
+    for item in dotenvy::from_path_iter(file_path).map_err(into_error)? {
+        let (key, value) = item.map_err(into_error)?;
+        // like when loading the file, the first declaration is applied
+        variables
+            .entry(key.trim_start_matches('\u{feff}').to_string())
+            .or_insert(value);
+    }
+    let content =
+        fs::read_to_string(file_path).map_err(|err| into_error(dotenvy::Error::Io(err)))?;
+    let mut lines = collections::HashMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim_start_matches('\u{feff}').trim_start();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((key, _)) = line.split_once('=')
+            && variables.contains_key(key.trim_end())
+        {
+            lines.entry(key.trim_end().to_string()).or_insert(index + 1);
+        }
+    }
+    let path = path::Path::new(file_path);
+    let env_file = EnvFile {
+        path: path.canonicalize().unwrap_or(path.to_path_buf()),
+        variables,
+        lines,
+    };
+    Ok(env_file)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -73,25 +496,129 @@ mod tests {
     use std::{fs, path};
 
     #[rstest]
-    #[case("KEY", "value", true, "key", Some("value"))]
-    #[case("KEY", "value", true, "KEY", None)]
-    #[case("KEY", "value", false, "key", None)]
-    #[case("KEY", "value", false, "KEY", Some("value"))]
+    #[case("KEY", "value", KeyMatching::CaseInsensitive, "key", Some("value"))]
+    #[case("KEY", "value", KeyMatching::CaseInsensitive, "KEY", None)]
+    #[case("KEY", "value", KeyMatching::Exact, "key", None)]
+    #[case("KEY", "value", KeyMatching::Exact, "KEY", Some("value"))]
+    #[case(
+        "APP_DB-HOST",
+        "value",
+        KeyMatching::Normalized,
+        "app_db_host",
+        Some("value")
+    )]
     fn test_get_env_variables(
         #[case] key: &str,
         #[case] value: &str,
-        #[case] case_insensitive: bool,
+        #[case] matching: KeyMatching,
         #[case] recover_key: &str,
         #[case] expected_result: Option<&str>,
     ) {
         unsafe {
             env::set_var(key, value);
         }
-        let env_variables = get_env_variables(case_insensitive);
-        let actual_result = env_variables.get(recover_key).map(|value| value.as_str());
+        let env_variables = get_env_variables(matching);
+        let actual_result = env_variables
+            .get(recover_key)
+            .expect("Test environment variable is ambiguous!")
+            .and_then(|value| value.to_str());
+        assert_eq!(actual_result, expected_result);
+    }
+
+    #[rstest]
+    #[case(KeyMatching::Exact, "APP_DB-HOST", "APP_DB-HOST")]
+    #[case(KeyMatching::CaseInsensitive, "APP_DB-HOST", "app_db-host")]
+    #[case(KeyMatching::Normalized, "APP_DB-HOST", "app_db_host")]
+    #[case(KeyMatching::Normalized, "app.db-host", "app_db_host")]
+    fn test_normalize_key(
+        #[case] matching: KeyMatching,
+        #[case] key: &str,
+        #[case] expected_result: &str,
+    ) {
+        assert_eq!(matching.normalize(key), expected_result);
+    }
+
+    #[rstest]
+    #[case(&[("Port", "80"), ("PORT", "8080")], KeyMatching::Exact, "PORT", Ok(Some("8080")))]
+    #[case(
+        &[("Port", "80"), ("PORT", "8080")],
+        KeyMatching::CaseInsensitive,
+        "port",
+        Err(EnvSettingsError::Ambiguous("port".to_string(), vec!["PORT".to_string(), "Port".to_string()]))
+    )]
+    #[case(&[("Port", "80"), ("PORT", "80")], KeyMatching::CaseInsensitive, "port", Ok(Some("80")))]
+    #[case(
+        &[("app.db-host", "lorem"), ("APP_DB_HOST", "ipsum")],
+        KeyMatching::Normalized,
+        "app_db_host",
+        Err(EnvSettingsError::Ambiguous("app_db_host".to_string(), vec!["APP_DB_HOST".to_string(), "app.db-host".to_string()]))
+    )]
+    #[case(&[("app.db-host", "lorem")], KeyMatching::Normalized, "app_db_host", Ok(Some("lorem")))]
+    #[case(&[("Port", "80"), ("PORT", "8080")], KeyMatching::CaseInsensitive, "host", Ok(None))]
+    fn test_env_variables_get(
+        #[case] variables: &[(&str, &str)],
+        #[case] matching: KeyMatching,
+        #[case] key: &str,
+        #[case] expected_result: EnvSettingsResult<Option<&str>>,
+    ) {
+        let env_variables = EnvVariables::new(
+            variables
+                .iter()
+                .map(|(key, value)| (key.to_string(), ffi::OsString::from(value))),
+            matching,
+        );
+        let actual_result = env_variables
+            .get(key)
+            .map(|value| value.and_then(|value| value.to_str()));
         assert_eq!(actual_result, expected_result);
     }
 
+    #[rstest]
+    #[case(&["DB_URL", "DATABASE_URL", "PG_URL"], Some(("DB_URL", "db")))]
+    #[case(&["DATABASE_URL", "PG_URL"], Some(("DATABASE_URL", "database")))]
+    #[case(&["PG_URL", "DATABASE_URL"], Some(("PG_URL", "pg")))]
+    #[case(&["OTHER_URL", "PG_URL"], Some(("PG_URL", "pg")))]
+    #[case(&["OTHER_URL"], None)]
+    fn test_find_env_variable(
+        #[case] variables: &[&str],
+        #[case] expected_result: Option<(&str, &str)>,
+    ) {
+        let env_variables = EnvVariables::new(
+            [
+                ("DB_URL".to_string(), ffi::OsString::from("db")),
+                ("DATABASE_URL".to_string(), ffi::OsString::from("database")),
+                ("PG_URL".to_string(), ffi::OsString::from("pg")),
+            ],
+            KeyMatching::Exact,
+        );
+        let actual_result = find_env_variable(&env_variables, variables)
+            .expect("Test environment variable is ambiguous!")
+            .map(|(variable, value)| (variable, value.to_str().unwrap_or_default()));
+        assert_eq!(actual_result, expected_result);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_env_variables_not_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = ffi::OsStr::from_bytes(b"\xff");
+        unsafe {
+            env::set_var("NOT_UNICODE_KEY", value);
+        }
+        let env_variables = get_env_variables(KeyMatching::Exact);
+        let actual_result = env_variables.get("NOT_UNICODE_KEY");
+        assert_eq!(
+            actual_result.map(|value| value.map(|value| value.as_os_str())),
+            Ok(Some(value))
+        );
+        assert_eq!(
+            to_str(value, "NOT_UNICODE_KEY"),
+            Err(EnvSettingsError::NotUnicode("NOT_UNICODE_KEY".to_string()))
+        );
+        assert_eq!(to_str(ffi::OsStr::new("value"), "KEY"), Ok("value"));
+    }
+
     #[rstest]
     #[case("KEY", "value", Some("file_path"))]
     #[case("KEY", "value", None)]
@@ -109,7 +636,7 @@ mod tests {
             fs::create_dir_all(&temp_dir)
                 .expect("Error occurs while creating the test temp directory!");
             let temp_file_path = temp_dir.join("test_file");
-            let pair = format!("{key}={value}\n");
+            let pair = format!("# comment\n\nexport {key}={value}\n");
             let mut temp_file = fs::File::create(&temp_file_path)
                 .expect("Error occurs while creating the test temp file!");
             temp_file
@@ -121,8 +648,121 @@ mod tests {
         if is_successful {
             let actual_value = env::var(key).expect("Test environment variable not set!");
             assert_eq!(actual_value, value);
+            let env_file = actual_result.expect("Test environment variables file not loaded!");
+            assert!(env_file.path.is_absolute());
+            assert_eq!(
+                env_file.variables.get(key).map(|value| value.as_str()),
+                Some(value)
+            );
+            assert_eq!(env_file.lines.get(key), Some(&3));
         } else {
             assert!(actual_result.is_err())
         }
     }
+
+    #[rstest]
+    #[case("KEY", "value", KeyMatching::Exact, true)]
+    #[case("key", "value", KeyMatching::CaseInsensitive, true)]
+    #[case("key", "value", KeyMatching::Normalized, true)]
+    #[case("key", "value", KeyMatching::Exact, false)]
+    #[case("KEY", "other", KeyMatching::Exact, false)]
+    #[case("OTHER", "value", KeyMatching::Exact, false)]
+    fn test_env_file_supplies(
+        #[case] key: &str,
+        #[case] value: &str,
+        #[case] matching: KeyMatching,
+        #[case] expected_result: bool,
+    ) {
+        let env_file = EnvFile {
+            path: path::PathBuf::from("dir/.env"),
+            variables: collections::HashMap::from([("KEY".to_string(), "value".to_string())]),
+            lines: collections::HashMap::from([("KEY".to_string(), 2)]),
+        };
+        assert_eq!(env_file.dir(), path::Path::new("dir"));
+        assert_eq!(env_file.supplies(key, value, matching), expected_result);
+        let expected_origin = expected_result.then(|| Origin::File {
+            variable: "KEY".to_string(),
+            path: path::PathBuf::from("dir/.env").into(),
+            line: 2,
+        });
+        assert_eq!(env_file.origin(key, value, matching), expected_origin);
+    }
+
+    #[rstest]
+    #[case("invalid value `hunter2`", "hunter2", "invalid value `***`")]
+    #[case(
+        "invalid digit found in string",
+        "hunter2",
+        "invalid digit found in string"
+    )]
+    #[case("invalid value ``", "", "invalid value ``")]
+    fn test_redact(#[case] message: &str, #[case] value: &str, #[case] expected_result: &str) {
+        assert_eq!(redact(message, value), expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        vec![EnvSettingsError::NotUnicode("NAME".to_string())],
+        EnvSettingsError::NotUnicode("NAME".to_string()),
+        "Environment variable named `NAME` is not valid unicode"
+    )]
+    #[case(
+        vec![EnvSettingsError::NotUnicode("NAME".to_string()), EnvSettingsError::Empty("AGE".to_string())],
+        EnvSettingsError::Multiple(vec![EnvSettingsError::NotUnicode("NAME".to_string()), EnvSettingsError::Empty("AGE".to_string())]),
+        "2 errors occur while resolving the environment variables:\n  - Environment variable named `NAME` is not valid unicode\n  - Environment variable named `AGE` is empty"
+    )]
+    #[case(
+        vec![EnvSettingsError::Convert { field: "port", value: "x".to_string(), origin: Origin::Environment("PORT".to_string()), type_name: "u16", source: "invalid digit found in string".into(), snippet: None }],
+        EnvSettingsError::Convert { field: "port", value: "x".to_string(), origin: Origin::Environment("PORT".to_string()), type_name: "u16", source: "invalid digit found in string".into(), snippet: None },
+        "Unable to convert the field `port`: `x` from the environment variable `PORT` to `u16`"
+    )]
+    #[case(
+        vec![EnvSettingsError::Convert { field: "port", value: "x".to_string(), origin: Origin::Environment("PORT".to_string()), type_name: "u16", source: "invalid digit found in string".into(), snippet: None }, EnvSettingsError::Empty("AGE".to_string())],
+        EnvSettingsError::Multiple(vec![EnvSettingsError::Convert { field: "port", value: "x".to_string(), origin: Origin::Environment("PORT".to_string()), type_name: "u16", source: "invalid digit found in string".into(), snippet: None }, EnvSettingsError::Empty("AGE".to_string())]),
+        "2 errors occur while resolving the environment variables:\n  - Unable to convert the field `port`: `x` from the environment variable `PORT` to `u16`: invalid digit found in string\n  - Environment variable named `AGE` is empty"
+    )]
+    fn test_from_errors(
+        #[case] errors: Vec<EnvSettingsError>,
+        #[case] expected_result: EnvSettingsError,
+        #[case] expected_message: &str,
+    ) {
+        let actual_result = EnvSettingsError::from_errors(errors);
+        assert_eq!(actual_result.to_string(), expected_message);
+        assert_eq!(actual_result, expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "name",
+            variables: vec!["NAME".to_string()],
+            matching: KeyMatching::Exact,
+            sources: vec![EnvSource::Environment],
+            suggestion: None,
+        },
+        "Environment variable for the field `name` not found: looked up `NAME` in the process environment"
+    )]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "database_url",
+            variables: vec!["app_database_url".to_string(), "database_url".to_string()],
+            matching: KeyMatching::CaseInsensitive,
+            sources: vec![EnvSource::Environment, EnvSource::File(path::PathBuf::from("/app/.env"))],
+            suggestion: Some("APP_DATABSE_URL".to_string()),
+        },
+        "Environment variable for the field `database_url` not found: looked up `app_database_url`, `database_url` ignoring case in the process environment and `/app/.env`, did you mean `APP_DATABSE_URL`?"
+    )]
+    #[case(
+        EnvSettingsError::NotExists {
+            field: "database_url",
+            variables: vec!["app_database_url".to_string()],
+            matching: KeyMatching::Normalized,
+            sources: vec![EnvSource::Environment],
+            suggestion: None,
+        },
+        "Environment variable for the field `database_url` not found: looked up `app_database_url` ignoring case and separators in the process environment"
+    )]
+    fn test_not_exists(#[case] error: EnvSettingsError, #[case] expected_result: &str) {
+        assert_eq!(error.to_string(), expected_result);
+    }
 }
@@ -0,0 +1,9 @@
+/// A type that can be used for optional fields, like the aliases of `Option<T>`
+pub trait Optional {
+    /// The type of the value
+    type Inner;
+}
+
+impl<T> Optional for Option<T> {
+    type Inner = T;
+}
@@ -0,0 +1,8 @@
+use std::{convert, ffi};
+
+/// Parse an `OsString`, keeping non unicode values untouched
+pub fn parse_os_string(
+    value: &(impl AsRef<ffi::OsStr> + ?Sized),
+) -> Result<ffi::OsString, convert::Infallible> {
+    Ok(value.as_ref().to_os_string())
+}
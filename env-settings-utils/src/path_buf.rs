@@ -0,0 +1,53 @@
+use std::{convert, env, ffi, path};
+
+/// Parse a path, expanding a leading `~` to the home directory and resolving relative paths against a base directory.
+///
+/// If the base directory is not set, relative paths are kept relative (to the current working directory)
+pub fn parse_path(
+    value: &(impl AsRef<ffi::OsStr> + ?Sized),
+    base_dir: Option<&path::Path>,
+) -> Result<path::PathBuf, convert::Infallible> {
+    let path = path::Path::new(value);
+    let path = match (path.strip_prefix("~"), env::home_dir()) {
+        (Ok(rest), Some(home_dir)) if rest.as_os_str().is_empty() => home_dir,
+        (Ok(rest), Some(home_dir)) => home_dir.join(rest),
+        _ => path.to_path_buf(),
+    };
+    let path = match base_dir {
+        Some(base_dir) if path.is_relative() => base_dir.join(path),
+        _ => path,
+    };
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("certs/server.pem", None, path::PathBuf::from("certs/server.pem"))]
+    #[case(
+        "certs/server.pem",
+        Some("/app"),
+        path::PathBuf::from("/app/certs/server.pem")
+    )]
+    #[case(
+        "/certs/server.pem",
+        Some("/app"),
+        path::PathBuf::from("/certs/server.pem")
+    )]
+    #[case("~", Some("/app"), env::home_dir().unwrap())]
+    #[case("~/certs", None, env::home_dir().unwrap().join("certs"))]
+    #[case("~lorem/certs", None, path::PathBuf::from("~lorem/certs"))]
+    fn test_parse_path(
+        #[case] value: &str,
+        #[case] base_dir: Option<&str>,
+        #[case] expected_result: path::PathBuf,
+    ) {
+        let actual_result = parse_path(value, base_dir.map(path::Path::new));
+        assert_eq!(actual_result, Ok(expected_result));
+    }
+}
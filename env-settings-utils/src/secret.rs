@@ -0,0 +1,89 @@
+use std::{fmt, str};
+use zeroize::Zeroize;
+
+/// A secret value, like a password or a token, that is never printed and is zeroized on drop
+///
+/// Both `Debug` and `Display` print `***`, the value can be read only with `Secret::expose`
+#[derive(Clone, Default, PartialEq)]
+pub struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Secret<T> {
+    /// Wrap a secret value
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Get the secret value
+    pub const fn expose(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***")
+    }
+}
+
+impl<T: Zeroize> fmt::Display for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***")
+    }
+}
+
+impl<T: Zeroize + str::FromStr> str::FromStr for Secret<T> {
+    type Err = T::Err;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("hunter2", Ok(Secret::new("hunter2".to_string())))]
+    #[case("", Ok(Secret::new(String::new())))]
+    fn test_parse_secret_string(
+        #[case] value: &str,
+        #[case] expected_result: Result<Secret<String>, std::convert::Infallible>,
+    ) {
+        assert_eq!(value.parse::<Secret<String>>(), expected_result);
+    }
+
+    #[rstest]
+    #[case("42", Some(42))]
+    #[case("other", None)]
+    fn test_parse_secret_number(#[case] value: &str, #[case] expected_result: Option<u16>) {
+        let actual_result = value.parse::<Secret<u16>>().ok();
+        assert_eq!(
+            actual_result.as_ref().map(Secret::expose),
+            expected_result.as_ref()
+        );
+    }
+
+    #[rstest]
+    #[case(Secret::new("hunter2".to_string()))]
+    #[case(Secret::new(String::new()))]
+    fn test_format_secret(#[case] secret: Secret<String>) {
+        assert_eq!(format!("{secret:?}"), "***");
+        assert_eq!(format!("{secret}"), "***");
+    }
+}
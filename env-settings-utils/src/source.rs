@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// The message used as source when the parsing error cannot be displayed
+pub const INVALID_VALUE: &str = "invalid value";
+
+/// A reference to a parsing error, to pick how to convert it into the source of `EnvSettingsError::Convert`.
+///
+/// The conversion is chosen by the method resolution, from the most to the least specific:
+/// the error itself, its message or a generic message
+/// (e.g. `(&&&ConvertSourceRef(&err)).convert_source_kind().convert_source(err)`)
+pub struct ConvertSourceRef<'a, E>(pub &'a E);
+
+/// Keep the parsing error as the source
+pub struct ErrorSource;
+
+impl ErrorSource {
+    /// Convert the parsing error into the source
+    pub fn convert_source<E: Error + Send + Sync + 'static>(
+        self,
+        err: E,
+    ) -> Box<dyn Error + Send + Sync> {
+        Box::new(err)
+    }
+}
+
+/// Use the message of the parsing error as the source
+pub struct DisplaySource;
+
+impl DisplaySource {
+    /// Convert the parsing error into the source
+    pub fn convert_source<E: Display>(self, err: E) -> Box<dyn Error + Send + Sync> {
+        err.to_string().into()
+    }
+}
+
+/// Use a generic message as the source
+pub struct FallbackSource;
+
+impl FallbackSource {
+    /// Convert the parsing error into the source
+    pub fn convert_source<E>(self, _err: E) -> Box<dyn Error + Send + Sync> {
+        INVALID_VALUE.into()
+    }
+}
+
+/// The parsing errors that are errors
+pub trait ErrorSourceKind {
+    /// Get how to convert the parsing error
+    fn convert_source_kind(&self) -> ErrorSource {
+        ErrorSource
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> ErrorSourceKind for &&ConvertSourceRef<'_, E> {}
+
+/// The parsing errors that can only be displayed
+pub trait DisplaySourceKind {
+    /// Get how to convert the parsing error
+    fn convert_source_kind(&self) -> DisplaySource {
+        DisplaySource
+    }
+}
+
+impl<E: Display> DisplaySourceKind for &ConvertSourceRef<'_, E> {}
+
+/// The other parsing errors
+pub trait FallbackSourceKind {
+    /// Get how to convert the parsing error
+    fn convert_source_kind(&self) -> FallbackSource {
+        FallbackSource
+    }
+}
+
+impl<E> FallbackSourceKind for ConvertSourceRef<'_, E> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct DisplayError;
+
+    impl Display for DisplayError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "display error")
+        }
+    }
+
+    // the references are required by the method resolution
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn test_convert_source() {
+        let err = "a".parse::<u8>().unwrap_err();
+        let source = (&&&ConvertSourceRef(&err))
+            .convert_source_kind()
+            .convert_source(err);
+        assert!(source.downcast_ref::<std::num::ParseIntError>().is_some());
+
+        let source = (&&&ConvertSourceRef(&DisplayError))
+            .convert_source_kind()
+            .convert_source(DisplayError);
+        assert_eq!(source.to_string(), "display error");
+
+        let source = (&&&ConvertSourceRef(&()))
+            .convert_source_kind()
+            .convert_source(());
+        assert_eq!(source.to_string(), INVALID_VALUE);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{EnvVariables, KeyMatching};
+
+use std::cmp;
+
+/// Compute the edit distance between two names
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<char>>();
+    let mut previous_row = (0..=right.len()).collect::<Vec<usize>>();
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut row = vec![left_index + 1];
+        for (right_index, right_char) in right.iter().enumerate() {
+            let substitution = previous_row[right_index] + usize::from(left_char != *right_char);
+            let insertion = row[right_index] + 1;
+            let deletion = previous_row[right_index + 1] + 1;
+            row.push(cmp::min(substitution, cmp::min(insertion, deletion)));
+        }
+        previous_row = row;
+    }
+    previous_row[right.len()]
+}
+
+/// Whether a name is the other one with an additional prefix.
+///
+/// The shorter name must be made of several words, otherwise any variable ending with a common word
+/// (e.g. `CARGO_PKG_NAME` for `name`) would match
+fn is_prefix_variation(left: &str, right: &str) -> bool {
+    let (shorter, longer) = if left.len() < right.len() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    shorter.contains('_') && longer.ends_with(&format!("_{shorter}"))
+}
+
+/// The maximum edit distance for a word to be considered a typo of another one
+fn max_distance(word: &str) -> usize {
+    cmp::max(word.chars().count(), 3) / 3
+}
+
+/// Whether a name is a typo of the other one, that is they differ by a small edit distance in a single word
+/// or by a single character
+fn is_typo(left: &str, right: &str) -> Option<usize> {
+    let distance = edit_distance(left, right);
+    if distance <= 1 {
+        return Some(distance);
+    }
+    let left_words = left.split('_').collect::<Vec<&str>>();
+    let right_words = right.split('_').collect::<Vec<&str>>();
+    if left_words.len() != right_words.len() {
+        return None;
+    }
+    let mut different_words = left_words
+        .iter()
+        .zip(right_words.iter())
+        .filter(|(left_word, right_word)| left_word != right_word);
+    match (different_words.next(), different_words.next()) {
+        (Some((left_word, right_word)), None)
+            if edit_distance(left_word, right_word) <= max_distance(left_word) =>
+        {
+            Some(distance)
+        }
+        _ => None,
+    }
+}
+
+/// Find the environment variable set most similar to a name that is not found.
+///
+/// The names are compared ignoring case and separators, so the variables differing only by case or
+/// separators are preferred, then the ones with a typo and finally the ones differing by a prefix
+/// (e.g. `DATABASE_URL` for `APP_DATABASE_URL`)
+pub fn suggest_env_variable(env_variables: &EnvVariables, name: &str) -> Option<String> {
+    let name = KeyMatching::Normalized.normalize(name);
+    let compact_name = name.replace('_', "");
+    env_variables
+        .names()
+        .filter_map(|candidate| {
+            let normalized_candidate = KeyMatching::Normalized.normalize(candidate);
+            let rank = if normalized_candidate.replace('_', "") == compact_name {
+                (0, 0)
+            } else if let Some(distance) = is_typo(&name, &normalized_candidate) {
+                (1, distance)
+            } else if is_prefix_variation(&name, &normalized_candidate) {
+                (2, 0)
+            } else {
+                return None;
+            };
+            Some((rank, candidate))
+        })
+        .min()
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+    use std::ffi;
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("lorem", "lorem", 0)]
+    #[case("lorem", "lorme", 2)]
+    #[case("lorem", "loremipsum", 5)]
+    #[case("app_databse_url", "app_database_url", 1)]
+    fn test_edit_distance(#[case] left: &str, #[case] right: &str, #[case] expected_result: usize) {
+        assert_eq!(edit_distance(left, right), expected_result);
+    }
+
+    #[rstest]
+    #[case("APP_DATABSE_URL", vec!["APP_DATABASE_URL", "HOME"], Some("APP_DATABASE_URL"))]
+    #[case("APP_DATABASE_URL", vec!["app.database-url", "APP_DATABASE_URI"], Some("app.database-url"))]
+    #[case("camelCaseName", vec!["camel_case_name"], Some("camel_case_name"))]
+    #[case("APP_DATABASE_URL", vec!["APP_DATABASEURL", "DATABASE_URL"], Some("APP_DATABASEURL"))]
+    #[case("APP_MAX_CONECTIONS", vec!["APP_MAX_CONNECTIONS"], Some("APP_MAX_CONNECTIONS"))]
+    #[case("APP_DATABASE_URL", vec!["DATABASE_URL"], Some("DATABASE_URL"))]
+    #[case("DATABASE_URL", vec!["MY_SERVICE_DATABASE_URL"], Some("MY_SERVICE_DATABASE_URL"))]
+    #[case("APP_DATABASE_URL", vec!["APP_LOG_LEVEL", "HOME"], None)]
+    #[case("TEST_age", vec!["TEST_name"], None)]
+    #[case("age", vec!["page", "name"], Some("page"))]
+    #[case("name", vec!["CARGO_PKG_NAME"], None)]
+    #[case("age", vec![], None)]
+    fn test_suggest_env_variable(
+        #[case] name: &str,
+        #[case] env_variables: Vec<&str>,
+        #[case] expected_result: Option<&str>,
+    ) {
+        let env_variables = EnvVariables::new(
+            env_variables
+                .into_iter()
+                .map(|key| (key.to_string(), ffi::OsString::from("lorem"))),
+            KeyMatching::Exact,
+        );
+        assert_eq!(
+            suggest_env_variable(&env_variables, name),
+            expected_result.map(str::to_string)
+        );
+    }
+}
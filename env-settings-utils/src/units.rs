@@ -0,0 +1,68 @@
+/// Split a value like `1.5GiB` into its numeric part (`1.5`) and its unit part (`GiB`)
+pub(crate) fn split_number(value: &str) -> (&str, &str) {
+    let index = value
+        .find(|char: char| !char.is_ascii_digit() && char != '.')
+        .unwrap_or(value.len());
+    value.split_at(index)
+}
+
+/// Multiply a decimal number (e.g. `1.5`) by a scale, rounding down the result
+pub(crate) fn scale_number(number: &str, scale: u128) -> Option<u128> {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .chars()
+        .chain(fraction.chars())
+        .all(|char| char.is_ascii_digit())
+    {
+        return None;
+    }
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<u128>().ok()?
+    };
+    let mut value = integer.checked_mul(scale)?;
+    if !fraction.is_empty() {
+        let fraction_digits = fraction.len().min(18) as u32;
+        let fraction = fraction[..fraction_digits as usize].parse::<u128>().ok()?;
+        let fraction_value = fraction.checked_mul(scale)? / 10u128.pow(fraction_digits);
+        value = value.checked_add(fraction_value)?;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.5GiB", ("1.5", "GiB"))]
+    #[case("30s", ("30", "s"))]
+    #[case("42", ("42", ""))]
+    #[case("ms", ("", "ms"))]
+    fn test_split_number(#[case] value: &str, #[case] expected_result: (&str, &str)) {
+        assert_eq!(split_number(value), expected_result);
+    }
+
+    #[rstest]
+    #[case("1", 1000, Some(1000))]
+    #[case("1.5", 1000, Some(1500))]
+    #[case(".5", 1000, Some(500))]
+    #[case("0.0001", 1000, Some(0))]
+    #[case("", 1000, None)]
+    #[case(".", 1000, None)]
+    #[case("1.2.3", 1000, None)]
+    fn test_scale_number(
+        #[case] number: &str,
+        #[case] scale: u128,
+        #[case] expected_result: Option<u128>,
+    ) {
+        assert_eq!(scale_number(number, scale), expected_result);
+    }
+}
@@ -0,0 +1,43 @@
+/// The error that may occurs while parsing an enum deriving `EnvSettingsEnum`
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+#[error("expected one of {}", .variants.iter().map(|variant| format!("`{variant}`")).collect::<Vec<_>>().join(", "))]
+pub struct ParseEnumError {
+    /// The accepted variants
+    pub variants: &'static [&'static str],
+}
+
+/// Normalize an enum variant, or a value to match against it, ignoring case, `-` and `_`
+pub fn normalize_variant(value: &str) -> String {
+    value
+        .chars()
+        .filter(|char| *char != '-' && *char != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("PrettyJson", "prettyjson")]
+    #[case("pretty-json", "prettyjson")]
+    #[case("PRETTY_JSON", "prettyjson")]
+    #[case("json", "json")]
+    fn test_normalize_variant(#[case] value: &str, #[case] expected_result: &str) {
+        assert_eq!(normalize_variant(value), expected_result);
+    }
+
+    #[rstest]
+    #[case(&["Json"], "expected one of `Json`")]
+    #[case(&["Json", "Text"], "expected one of `Json`, `Text`")]
+    fn test_display_parse_enum_error(
+        #[case] variants: &'static [&'static str],
+        #[case] expected_result: &str,
+    ) {
+        assert_eq!(ParseEnumError { variants }.to_string(), expected_result);
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync;
+
+/// The warning that may be raised during `EnvSettings` resolution
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum EnvSettingsWarning {
+    /// Warning raised when a value is read from a deprecated alias of an environment variable
+    #[error("Environment variable named `{0}` is deprecated, use `{1}` instead")]
+    DeprecatedAlias(String, String),
+}
+
+/// The function called with the warnings raised during `EnvSettings` resolution
+pub type WarningHook = fn(&EnvSettingsWarning);
+
+static WARNING_HOOK: sync::RwLock<Option<WarningHook>> = sync::RwLock::new(None);
+
+/// Set the function called with the warnings, like a `log::warn!` or `tracing::warn!` wrapper.
+///
+/// This is the only way to see the warnings: by default, they are ignored
+pub fn set_warning_hook(hook: WarningHook) {
+    *WARNING_HOOK
+        .write()
+        .unwrap_or_else(sync::PoisonError::into_inner) = Some(hook);
+}
+
+/// Raise a warning, calling the hook if set
+pub fn warn(warning: EnvSettingsWarning) {
+    let hook = *WARNING_HOOK
+        .read()
+        .unwrap_or_else(sync::PoisonError::into_inner);
+    if let Some(hook) = hook {
+        hook(&warning);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    use rstest::rstest;
+
+    static WARNINGS: sync::Mutex<Vec<EnvSettingsWarning>> = sync::Mutex::new(Vec::new());
+
+    #[rstest]
+    #[case(
+        EnvSettingsWarning::DeprecatedAlias("PG_URL".to_string(), "DB_URL".to_string()),
+        "Environment variable named `PG_URL` is deprecated, use `DB_URL` instead"
+    )]
+    fn test_warn(#[case] warning: EnvSettingsWarning, #[case] expected_result: &str) {
+        set_warning_hook(|warning| WARNINGS.lock().unwrap().push(warning.to_owned()));
+        warn(warning.to_owned());
+        assert_eq!(warning.to_string(), expected_result);
+        assert!(WARNINGS.lock().unwrap().contains(&warning));
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{
+        EnvSettingsError, EnvSettingsResult, EnvSettingsWarning, EnvSource, KeyMatching, Origin,
+    };
+    use rstest::rstest;
+    use std::{collections::HashMap, sync::Mutex};
+
+    static WARNINGS: Mutex<Vec<EnvSettingsWarning>> = Mutex::new(Vec::new());
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        #[env_settings(
+            variable = "DB_URL",
+            alias = "DATABASE_URL",
+            deprecated_alias = "PG_URL, POSTGRES_URL"
+        )]
+        database_url: String,
+
+        #[env_settings(alias = "MAX_CONNECTIONS", empty = "reject")]
+        max_connections: Option<u8>,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    struct TestCompileTimeEnvSettings {
+        // `CARGO_PKG_NAME` is set by cargo while compiling
+        #[env_settings(variable = "TEST_ENV_SETTINGS_NOT_EXISTS", alias = "CARGO_PKG_NAME")]
+        name: String,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "database_url", variables: vec!["DB_URL".to_string(), "DATABASE_URL".to_string(), "PG_URL".to_string(), "POSTGRES_URL".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }),
+        vec![]
+    )]
+    #[case(
+        HashMap::from([("DB_URL", "db"), ("DATABASE_URL", "database"), ("PG_URL", "pg")]),
+        Ok(TestEnvSettings { database_url: "db".to_string(), max_connections: None }),
+        vec![]
+    )]
+    #[case(
+        HashMap::from([("DATABASE_URL", "database"), ("PG_URL", "pg")]),
+        Ok(TestEnvSettings { database_url: "database".to_string(), max_connections: None }),
+        vec![]
+    )]
+    #[case(
+        HashMap::from([("POSTGRES_URL", "postgres"), ("PG_URL", "pg")]),
+        Ok(TestEnvSettings { database_url: "pg".to_string(), max_connections: None }),
+        vec![EnvSettingsWarning::DeprecatedAlias("PG_URL".to_string(), "DB_URL".to_string())]
+    )]
+    #[case(
+        HashMap::from([("POSTGRES_URL", "postgres")]),
+        Ok(TestEnvSettings { database_url: "postgres".to_string(), max_connections: None }),
+        vec![EnvSettingsWarning::DeprecatedAlias("POSTGRES_URL".to_string(), "DB_URL".to_string())]
+    )]
+    #[case(
+        HashMap::from([("DB_URL", "db"), ("MAX_CONNECTIONS", "42")]),
+        Ok(TestEnvSettings { database_url: "db".to_string(), max_connections: Some(42) }),
+        vec![]
+    )]
+    #[case(
+        HashMap::from([("DB_URL", "db"), ("MAX_CONNECTIONS", "")]),
+        Err(EnvSettingsError::Empty("MAX_CONNECTIONS".to_string())),
+        vec![]
+    )]
+    #[case(
+        HashMap::from([("DB_URL", "db"), ("MAX_CONNECTIONS", "lorem")]),
+        Err(EnvSettingsError::Convert { field: "max_connections", value: "lorem".to_string(), origin: Origin::Environment("MAX_CONNECTIONS".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None }),
+        vec![]
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+        #[case] expected_warnings: Vec<EnvSettingsWarning>,
+    ) {
+        env_settings_utils::set_warning_hook(|warning| {
+            WARNINGS.lock().unwrap().push(warning.to_owned());
+        });
+        WARNINGS.lock().unwrap().clear();
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+        assert_eq!(*WARNINGS.lock().unwrap(), expected_warnings);
+    }
+
+    #[test]
+    fn test_from_env_compile_time() {
+        let expected_result = TestCompileTimeEnvSettings {
+            name: "test-env-settings".to_string(),
+        };
+        assert_eq!(TestCompileTimeEnvSettings::from_env(), Ok(expected_result));
+    }
+}
@@ -4,9 +4,9 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
     use rstest::rstest;
-    use std::collections::HashMap;
+    use std::{collections::HashMap, error, num};
 
     #[derive(Debug, EnvSettings, PartialEq)]
     #[env_settings(delay)]
@@ -18,15 +18,15 @@ mod tests {
     #[rstest]
     #[case(
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("age", "42")]),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -34,7 +34,7 @@ mod tests {
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "other")]),
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_from_env(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -48,7 +48,7 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -60,7 +60,7 @@ mod tests {
         HashMap::from([]),
         None,
         Some(42),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
@@ -72,7 +72,7 @@ mod tests {
         HashMap::from([]),
         Some("lorem".to_string()),
         None,
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("age", "42")]),
@@ -96,7 +96,7 @@ mod tests {
         HashMap::from([("name", "lorem"), ("age", "other")]),
         None,
         None,
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_new(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -110,4 +110,101 @@ mod tests {
             &expected_result,
         );
     }
+
+    #[rstest]
+    #[case(
+        "other",
+        "invalid digit found in string",
+        num::IntErrorKind::InvalidDigit
+    )]
+    #[case(
+        "300",
+        "number too large to fit in target type",
+        num::IntErrorKind::PosOverflow
+    )]
+    fn test_from_env_source(
+        #[case] age: &'static str,
+        #[case] message: &'static str,
+        #[case] expected_result: num::IntErrorKind,
+    ) {
+        let actual_result = with_env_variables(
+            &HashMap::from([("name", "lorem"), ("age", age)]),
+            TestEnvSettings::from_env,
+            &Err(EnvSettingsError::Convert {
+                field: "age",
+                value: age.to_string(),
+                origin: Origin::Environment("age".to_string()),
+                type_name: "u8",
+                source: message.into(),
+                snippet: None,
+            }),
+        );
+        let source = error::Error::source(&actual_result.unwrap_err())
+            .and_then(|source| source.downcast_ref::<num::ParseIntError>())
+            .map(|source| source.kind().to_owned());
+        assert_eq!(source, Some(expected_result));
+    }
+
+    /// A type whose parsing error is not an error
+    #[derive(Debug, PartialEq)]
+    struct Level(u8);
+
+    impl std::str::FromStr for Level {
+        type Err = ();
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            value.parse().map(Level).map_err(|_| ())
+        }
+    }
+
+    /// A type whose parsing error can only be displayed
+    #[derive(Debug, PartialEq)]
+    struct Ratio(u8);
+
+    struct RatioError;
+
+    impl std::fmt::Display for RatioError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "expected a ratio")
+        }
+    }
+
+    impl std::str::FromStr for Ratio {
+        type Err = RatioError;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            value.parse().map(Ratio).map_err(|_| RatioError)
+        }
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestNotErrorEnvSettings {
+        level: Level,
+        ratio: Ratio,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("level", "1"), ("ratio", "2")]),
+        Ok(TestNotErrorEnvSettings { level: Level(1), ratio: Ratio(2) })
+    )]
+    #[case(
+        HashMap::from([("level", "high"), ("ratio", "2")]),
+        Err(EnvSettingsError::Convert { field: "level", value: "high".to_string(), origin: Origin::Environment("level".to_string()), type_name: "Level", source: "invalid value".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("level", "1"), ("ratio", "half")]),
+        Err(EnvSettingsError::Convert { field: "ratio", value: "half".to_string(), origin: Origin::Environment("ratio".to_string()), type_name: "Ratio", source: "expected a ratio".into(), snippet: None })
+    )]
+    fn test_from_env_not_error(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestNotErrorEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestNotErrorEnvSettings::from_env,
+            &expected_result,
+        );
+    }
 }
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{
+        ByteSize, EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin,
+    };
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        cache_size: ByteSize,
+
+        #[env_settings(default = "2GB")]
+        body_limit: ByteSize,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "cache_size", variables: vec!["cache_size".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("cache_size", "512MiB")]),
+        Ok(TestEnvSettings { cache_size: ByteSize(512 * 1024 * 1024), body_limit: ByteSize(2_000_000_000) })
+    )]
+    #[case(
+        HashMap::from([("cache_size", "1KiB"), ("body_limit", "10kb")]),
+        Ok(TestEnvSettings { cache_size: ByteSize(1024), body_limit: ByteSize(10_000) })
+    )]
+    #[case(
+        HashMap::from([("cache_size", "lots")]),
+        Err(EnvSettingsError::Convert { field: "cache_size", value: "lots".to_string(), origin: Origin::Environment("cache_size".to_string()), type_name: "ByteSize", source: "unknown unit `lots`, expected a unit like `B`, `KB`, `KiB`, `MB`, `MiB`, `GB` or `GiB`".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+}
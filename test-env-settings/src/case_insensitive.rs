@@ -4,7 +4,7 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::EnvSettingsResult;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching};
     use rstest::rstest;
     use std::collections::HashMap;
 
@@ -15,11 +15,28 @@ mod tests {
         age: u8,
     }
 
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, normalize_separators, prefix = "app.")]
+    struct TestNormalizedEnvSettings {
+        db_host: String,
+
+        #[env_settings(default = 5432)]
+        db_port: u16,
+    }
+
     #[rstest]
     #[case(
         HashMap::from([("NAME", "lorem"), ("age", "42")]),
         Ok(TestEnvSettings { name: "lorem".to_string(), age: 42 })
     )]
+    #[case(
+        HashMap::from([("NAME", "lorem"), ("Name", "lorem"), ("age", "42")]),
+        Ok(TestEnvSettings { name: "lorem".to_string(), age: 42 })
+    )]
+    #[case(
+        HashMap::from([("NAME", "lorem"), ("Name", "ipsum"), ("age", "42")]),
+        Err(EnvSettingsError::Ambiguous("name".to_string(), vec!["NAME".to_string(), "Name".to_string()]))
+    )]
     fn test_from_env(
         #[case] env_variables: HashMap<&'static str, &'static str>,
         #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
@@ -46,4 +63,38 @@ mod tests {
             &expected_result,
         );
     }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("APP_DB_PORT", "6543")]),
+        Err(EnvSettingsError::NotExists {
+            field: "db_host",
+            variables: vec!["app_db_host".to_string()],
+            matching: KeyMatching::Normalized,
+            sources: vec![EnvSource::Environment],
+            suggestion: None,
+        })
+    )]
+    #[case(
+        HashMap::from([("APP_DB_HOST", "localhost")]),
+        Ok(TestNormalizedEnvSettings { db_host: "localhost".to_string(), db_port: 5432 })
+    )]
+    #[case(
+        HashMap::from([("app.db-host", "localhost"), ("App.Db.Port", "6543")]),
+        Ok(TestNormalizedEnvSettings { db_host: "localhost".to_string(), db_port: 6543 })
+    )]
+    #[case(
+        HashMap::from([("app.db-host", "localhost"), ("APP_DB_HOST", "127.0.0.1")]),
+        Err(EnvSettingsError::Ambiguous("app_db_host".to_string(), vec!["APP_DB_HOST".to_string(), "app.db-host".to_string()]))
+    )]
+    fn test_from_env_normalized(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestNormalizedEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestNormalizedEnvSettings::from_env,
+            &expected_result,
+        );
+    }
 }
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+
+    use env_settings_derive::EnvSettings;
+
+    // `CARGO_PKG_NAME` is set by cargo while compiling
+    #[derive(Debug, EnvSettings, PartialEq)]
+    struct TestEnvSettings {
+        #[env_settings(variable = "CARGO_PKG_NAME")]
+        name: String,
+
+        #[env_settings(default = "42")]
+        age: u8,
+    }
+
+    // without the `consts` parameter, the user constants do not collide with the fields
+    impl TestEnvSettings {
+        const AGE: u8 = 18;
+    }
+
+    #[test]
+    fn test_from_env() {
+        assert_eq!(TestEnvSettings::AGE, 18);
+        let expected_result = TestEnvSettings {
+            name: "test-env-settings".to_string(),
+            age: 42,
+        };
+        assert_eq!(TestEnvSettings::from_env(), Ok(expected_result));
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(consts)]
+    struct TestConstEnvSettings {
+        #[env_settings(variable = "CARGO_PKG_NAME")]
+        name: &'static str,
+
+        #[env_settings(variable = "CARGO_PKG_VERSION_MAJOR")]
+        major: u8,
+
+        #[env_settings(default = "8080")]
+        port: u16,
+
+        #[env_settings(default = true)]
+        debug: bool,
+
+        #[env_settings(variable = "TEST_ENV_SETTINGS_NOT_EXISTS")]
+        host: Option<&'static str>,
+    }
+
+    // the associated constants are usable in `const` contexts
+    const NAME: &str = TestConstEnvSettings::NAME;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(NAME, "test-env-settings");
+        assert_eq!(TestConstEnvSettings::MAJOR, 0);
+        assert_eq!(TestConstEnvSettings::PORT, 8080);
+        const { assert!(TestConstEnvSettings::DEBUG) };
+        assert_eq!(TestConstEnvSettings::HOST, None);
+        let expected_result = TestConstEnvSettings {
+            name: "test-env-settings",
+            major: 0,
+            port: 8080,
+            debug: true,
+            host: None,
+        };
+        assert_eq!(TestConstEnvSettings::from_env(), Ok(expected_result));
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestChronoEnvSettings {
+        #[env_settings(format = "%d/%m/%Y")]
+        birth_date: chrono::NaiveDate,
+
+        #[env_settings(default = "1970-01-01T00:00:00Z")]
+        started_at: chrono::DateTime<chrono::Utc>,
+
+        alarm: Option<chrono::NaiveTime>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("birth_date", "01/01/1970")]),
+        Ok(TestChronoEnvSettings {
+            birth_date: chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            started_at: chrono::DateTime::UNIX_EPOCH,
+            alarm: None,
+        })
+    )]
+    #[case(
+        HashMap::from([("birth_date", "01/01/1970"), ("started_at", "1970-01-01T02:00:00+02:00"), ("alarm", "07:30:00")]),
+        Ok(TestChronoEnvSettings {
+            birth_date: chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            started_at: chrono::DateTime::UNIX_EPOCH,
+            alarm: chrono::NaiveTime::from_hms_opt(7, 30, 0),
+        })
+    )]
+    #[case(
+        HashMap::from([("birth_date", "1970-01-01")]),
+        Err(EnvSettingsError::Convert { field: "birth_date", value: "1970-01-01".to_string(), origin: Origin::Environment("birth_date".to_string()), type_name: "chrono::NaiveDate", source: "input contains invalid characters, expected format `%d/%m/%Y`".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("birth_date", "01/01/1970"), ("started_at", "1970-01-01")]),
+        Err(EnvSettingsError::Convert { field: "started_at", value: "1970-01-01".to_string(), origin: Origin::Environment("started_at".to_string()), type_name: "chrono::DateTime", source: "premature end of input, expected format `RFC 3339`".into(), snippet: None })
+    )]
+    fn test_from_env_chrono(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestChronoEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestChronoEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestTimeEnvSettings {
+        #[env_settings(format = "[day]/[month]/[year]")]
+        birth_date: time::Date,
+
+        #[env_settings(default = "1970-01-01T00:00:00Z")]
+        started_at: time::OffsetDateTime,
+
+        alarm: Option<time::Time>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("birth_date", "01/01/1970")]),
+        Ok(TestTimeEnvSettings {
+            birth_date: time::macros::date!(1970-01-01),
+            started_at: time::OffsetDateTime::UNIX_EPOCH,
+            alarm: None,
+        })
+    )]
+    #[case(
+        HashMap::from([("birth_date", "01/01/1970"), ("alarm", "07:30:00")]),
+        Ok(TestTimeEnvSettings {
+            birth_date: time::macros::date!(1970-01-01),
+            started_at: time::OffsetDateTime::UNIX_EPOCH,
+            alarm: Some(time::macros::time!(07:30:00)),
+        })
+    )]
+    #[case(
+        HashMap::from([("birth_date", "1970-01-01")]),
+        Err(EnvSettingsError::Convert { field: "birth_date", value: "1970-01-01".to_string(), origin: Origin::Environment("birth_date".to_string()), type_name: "time::Date", source: "a character literal was not valid, expected format `[day]/[month]/[year]`".into(), snippet: None })
+    )]
+    fn test_from_env_time(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestTimeEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestTimeEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    /// A user type named like `time::Date`, parsed with its own `FromStr`
+    mod calendar {
+
+        #[derive(Debug, PartialEq)]
+        pub(super) struct Date(pub(super) u16);
+
+        impl std::str::FromStr for Date {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                value.parse().map(Date)
+            }
+        }
+    }
+
+    use calendar::Date;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestCustomEnvSettings {
+        year: Date,
+
+        #[env_settings(format = "[day]/[month]/[year]")]
+        birth_date: Option<time::Date>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("year", "1970")]),
+        Ok(TestCustomEnvSettings { year: Date(1970), birth_date: None })
+    )]
+    #[case(
+        HashMap::from([("year", "1970"), ("birth_date", "01/01/1970")]),
+        Ok(TestCustomEnvSettings { year: Date(1970), birth_date: Some(time::macros::date!(1970-01-01)) })
+    )]
+    #[case(
+        HashMap::from([("year", "1970-01-01")]),
+        Err(EnvSettingsError::Convert { field: "year", value: "1970-01-01".to_string(), origin: Origin::Environment("year".to_string()), type_name: "Date", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    fn test_from_env_custom(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestCustomEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestCustomEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+}
@@ -4,9 +4,10 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
     use rstest::rstest;
     use std::collections::HashMap;
+    use std::time::Duration;
 
     #[derive(Debug, EnvSettings, PartialEq)]
     #[env_settings(delay)]
@@ -17,10 +18,97 @@ mod tests {
         age: u8,
     }
 
+    fn default_workers() -> usize {
+        4
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestComputedEnvSettings {
+        #[env_settings(default_fn = "default_workers")]
+        workers: usize,
+
+        #[env_settings(default_expr = Duration::from_millis(15 * 1000), duration, trim)]
+        timeout: Duration,
+
+        #[env_settings(default)]
+        name: String,
+
+        #[env_settings(default_expr = vec![1, 2].len() as u16)]
+        port: Option<u16>,
+
+        #[env_settings(default = -1)]
+        offset: i32,
+
+        #[env_settings(default = true)]
+        verbose: bool,
+
+        #[env_settings(default = 24)]
+        label: String,
+    }
+
     #[rstest]
+    #[case(
+        HashMap::from([("timeout", "1")]),
+        Err(EnvSettingsError::Convert { field: "timeout", value: "1".to_string(), origin: Origin::Environment("timeout".to_string()), type_name: "Duration", source: "missing unit after `1`".into(), snippet: None })
+    )]
     #[case(
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("name"))
+        Ok(TestComputedEnvSettings { workers: 4, timeout: Duration::from_secs(15), name: String::new(), port: Some(2), offset: -1, verbose: true, label: "24".to_string() })
+    )]
+    #[case(
+        HashMap::from([("workers", "8"), ("timeout", "1m"), ("name", "lorem"), ("port", "8080"), ("offset", "3"), ("verbose", "false"), ("label", "lorem")]),
+        Ok(TestComputedEnvSettings { workers: 8, timeout: Duration::from_secs(60), name: "lorem".to_string(), port: Some(8080), offset: 3, verbose: false, label: "lorem".to_string() })
+    )]
+    #[case(
+        HashMap::from([("workers", "many")]),
+        Err(EnvSettingsError::Convert { field: "workers", value: "many".to_string(), origin: Origin::Environment("workers".to_string()), type_name: "usize", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    fn test_from_env_computed(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestComputedEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestComputedEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestExpressionEnvSettings {
+        #[env_settings(default_expr = i32::pow(2, 3), trim)]
+        power: i32,
+
+        #[env_settings(default_expr = std::collections::HashMap::<String, String>::new().len(), trim)]
+        count: usize,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Ok(TestExpressionEnvSettings { power: 8, count: 0 })
+    )]
+    #[case(
+        HashMap::from([("power", " 4 "), ("count", " 2 ")]),
+        Ok(TestExpressionEnvSettings { power: 4, count: 2 })
+    )]
+    fn test_from_env_expression(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestExpressionEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestExpressionEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
@@ -28,7 +116,7 @@ mod tests {
     )]
     #[case(
         HashMap::from([("age", "42")]),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -36,7 +124,7 @@ mod tests {
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "other")]),
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_from_env(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -50,7 +138,7 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -62,7 +150,7 @@ mod tests {
         HashMap::from([]),
         None,
         Some(42),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
@@ -98,7 +186,7 @@ mod tests {
         HashMap::from([("name", "lorem"), ("age", "other")]),
         None,
         None,
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_new(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -112,4 +200,9 @@ mod tests {
             &expected_result,
         );
     }
+
+    #[test]
+    fn test_not_parsable() {
+        trybuild::TestCases::new().compile_fail("ui/default_*.rs");
+    }
 }
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        address: (String, u16),
+
+        #[env_settings(default = "1,2,3")]
+        weights: [u8; 3],
+
+        #[env_settings(delimiter = ";")]
+        hosts: Option<[String; 2]>,
+
+        #[env_settings(delimiter = "x")]
+        size: Option<(u32, u32)>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "address", variables: vec!["address".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("address", "localhost:8080")]),
+        Ok(TestEnvSettings { address: ("localhost".to_string(), 8080), weights: [1, 2, 3], hosts: None, size: None })
+    )]
+    #[case(
+        HashMap::from([("address", "localhost:8080"), ("weights", "4, 5, 6"), ("hosts", "a;b"), ("size", "800x600")]),
+        Ok(TestEnvSettings { address: ("localhost".to_string(), 8080), weights: [4, 5, 6], hosts: Some(["a".to_string(), "b".to_string()]), size: Some((800, 600)) })
+    )]
+    #[case(
+        HashMap::from([("address", "localhost")]),
+        Err(EnvSettingsError::Convert { field: "address", value: "localhost".to_string(), origin: Origin::Environment("address".to_string()), type_name: "(String, u16)", source: "expected 2 values separated by `:`, got 1".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("address", "localhost:http")]),
+        Err(EnvSettingsError::Convert { field: "address", value: "localhost:http".to_string(), origin: Origin::Environment("address".to_string()), type_name: "(String, u16)", source: "invalid value `http` at position 1: invalid digit found in string".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("address", "localhost:8080"), ("weights", "4,5")]),
+        Err(EnvSettingsError::Convert { field: "weights", value: "4,5".to_string(), origin: Origin::Environment("weights".to_string()), type_name: "[u8; 3]", source: "expected 3 values separated by `,`, got 2".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+}
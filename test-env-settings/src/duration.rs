@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        #[env_settings(duration)]
+        timeout: Duration,
+
+        #[env_settings(default = "1h30m")]
+        interval: std::time::Duration,
+
+        #[env_settings(duration)]
+        grace_period: Option<Duration>,
+    }
+
+    /// A user type named like `std::time::Duration`, parsed with its own `FromStr`
+    mod units {
+
+        #[derive(Debug, PartialEq)]
+        pub(super) struct Duration(pub(super) u64);
+
+        impl std::str::FromStr for Duration {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                value.parse().map(Duration)
+            }
+        }
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestCustomEnvSettings {
+        timeout: units::Duration,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "timeout", variables: vec!["timeout".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("timeout", "30s")]),
+        Ok(TestEnvSettings {
+            timeout: Duration::from_secs(30),
+            interval: Duration::from_secs(5400),
+            grace_period: None,
+        })
+    )]
+    #[case(
+        HashMap::from([("timeout", "250ms"), ("interval", "5m"), ("grace_period", "1.5s")]),
+        Ok(TestEnvSettings {
+            timeout: Duration::from_millis(250),
+            interval: Duration::from_secs(300),
+            grace_period: Some(Duration::from_millis(1500)),
+        })
+    )]
+    #[case(
+        HashMap::from([("timeout", "30")]),
+        Err(EnvSettingsError::Convert { field: "timeout", value: "30".to_string(), origin: Origin::Environment("timeout".to_string()), type_name: "Duration", source: "missing unit after `30`".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(HashMap::from([("timeout", "30")]), Ok(TestCustomEnvSettings { timeout: units::Duration(30) }))]
+    #[case(
+        HashMap::from([("timeout", "30s")]),
+        Err(EnvSettingsError::Convert { field: "timeout", value: "30s".to_string(), origin: Origin::Environment("timeout".to_string()), type_name: "units::Duration", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    fn test_from_env_custom(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestCustomEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestCustomEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+}
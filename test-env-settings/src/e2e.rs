@@ -4,9 +4,9 @@ mod tests {
     use crate::tests::{with_env_file_variables, with_env_variables};
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
     use rstest::rstest;
-    use std::collections::HashMap;
+    use std::{collections::HashMap, env};
 
     const FILE_PATH: &str = "test.env";
 
@@ -24,7 +24,7 @@ mod tests {
 		FILE_PATH,
         HashMap::from([]),
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("test_name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["test_name".to_string()], matching: KeyMatching::CaseInsensitive, sources: vec![EnvSource::Environment, EnvSource::File(env::current_dir().unwrap().join(FILE_PATH))], suggestion: None })
     )]
     #[case(
 		FILE_PATH,
@@ -36,7 +36,7 @@ mod tests {
 		FILE_PATH,
         HashMap::from([("test_24_age", "42")]),
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("test_name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["test_name".to_string()], matching: KeyMatching::CaseInsensitive, sources: vec![EnvSource::Environment, EnvSource::File(env::current_dir().unwrap().join(FILE_PATH))], suggestion: None })
     )]
     #[case(
 		FILE_PATH,
@@ -66,7 +66,7 @@ mod tests {
 		FILE_PATH,
         HashMap::from([("TEST_name", "lorem"), ("test_24_age", "other")]),
         HashMap::from([]),
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("test_24_age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_from_env_with_prefix(
         #[case] file_path: &str,
@@ -89,7 +89,7 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("test_name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["test_name".to_string()], matching: KeyMatching::CaseInsensitive, sources: vec![EnvSource::Environment, EnvSource::File(env::current_dir().unwrap().join(FILE_PATH))], suggestion: None })
     )]
     #[case(
 		FILE_PATH,
@@ -105,7 +105,7 @@ mod tests {
         HashMap::from([]),
         None,
         Some(42),
-        Err(EnvSettingsError::NotExists("test_name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["test_name".to_string()], matching: KeyMatching::CaseInsensitive, sources: vec![EnvSource::Environment, EnvSource::File(env::current_dir().unwrap().join(FILE_PATH))], suggestion: None })
     )]
     #[case(
 		FILE_PATH,
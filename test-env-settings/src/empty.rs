@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, empty = "unset", trim)]
+    struct TestEnvSettings {
+        port: Option<u16>,
+
+        #[env_settings(default = "localhost")]
+        host: String,
+
+        #[env_settings(empty = "keep")]
+        greeting: String,
+
+        #[env_settings(empty = "reject")]
+        user: Option<String>,
+
+        proxy: Option<Option<String>>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("greeting", "")]),
+        Ok(TestEnvSettings {
+            port: None,
+            host: "localhost".to_string(),
+            greeting: "".to_string(),
+            user: None,
+            proxy: None,
+        })
+    )]
+    #[case(
+        HashMap::from([("port", ""), ("host", "  "), ("greeting", " "), ("proxy", "")]),
+        Ok(TestEnvSettings {
+            port: None,
+            host: "localhost".to_string(),
+            greeting: "".to_string(),
+            user: None,
+            proxy: Some(None),
+        })
+    )]
+    #[case(
+        HashMap::from([
+            ("port", " 8080 "),
+            ("host", "example.com"),
+            ("greeting", " hello "),
+            ("user", "lorem"),
+            ("proxy", "proxy.com"),
+        ]),
+        Ok(TestEnvSettings {
+            port: Some(8080),
+            host: "example.com".to_string(),
+            greeting: "hello".to_string(),
+            user: Some("lorem".to_string()),
+            proxy: Some(Some("proxy.com".to_string())),
+        })
+    )]
+    #[case(
+        HashMap::from([("greeting", ""), ("user", " ")]),
+        Err(EnvSettingsError::Empty("user".to_string()))
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("greeting", ""), ("proxy", "proxy.com")]),
+        Some(None),
+        Ok(TestEnvSettings {
+            port: None,
+            host: "localhost".to_string(),
+            greeting: "".to_string(),
+            user: None,
+            proxy: Some(None),
+        })
+    )]
+    #[case(
+        HashMap::from([("greeting", "")]),
+        None,
+        Ok(TestEnvSettings {
+            port: None,
+            host: "localhost".to_string(),
+            greeting: "".to_string(),
+            user: None,
+            proxy: None,
+        })
+    )]
+    fn test_new(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] proxy: Option<Option<String>>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            || TestEnvSettings::new(None, None, None, None, proxy.clone()),
+            &expected_result,
+        );
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        #[env_settings(encoding = "base64")]
+        signing_key: Vec<u8>,
+
+        #[env_settings(encoding = "hex")]
+        aes_key: [u8; 4],
+
+        #[env_settings(encoding = "hex")]
+        hmac_secret: Option<[u8; 2]>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("aes_key", "deadbeef")]),
+        Err(EnvSettingsError::NotExists { field: "signing_key", variables: vec!["signing_key".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("signing_key", "bG9yZW0="), ("aes_key", "DEADBEEF")]),
+        Ok(TestEnvSettings { signing_key: b"lorem".to_vec(), aes_key: [0xde, 0xad, 0xbe, 0xef], hmac_secret: None })
+    )]
+    #[case(
+        HashMap::from([("signing_key", "bG9yZW0"), ("aes_key", "deadbeef"), ("hmac_secret", "cafe")]),
+        Ok(TestEnvSettings { signing_key: b"lorem".to_vec(), aes_key: [0xde, 0xad, 0xbe, 0xef], hmac_secret: Some([0xca, 0xfe]) })
+    )]
+    #[case(
+        HashMap::from([("signing_key", "not base64!"), ("aes_key", "deadbeef")]),
+        Err(EnvSettingsError::Convert { field: "signing_key", value: "***".to_string(), origin: Origin::Environment("signing_key".to_string()), type_name: "Vec", source: "invalid base64 value".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("signing_key", "bG9yZW0="), ("aes_key", "deadbe")]),
+        Err(EnvSettingsError::Convert { field: "aes_key", value: "***".to_string(), origin: Origin::Environment("aes_key".to_string()), type_name: "[u8; 4]", source: "expected 4 bytes, got 3".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("signing_key", "bG9yZW0="), ("aes_key", "deadbeef"), ("hmac_secret", "cafebabe")]),
+        Err(EnvSettingsError::Convert { field: "hmac_secret", value: "***".to_string(), origin: Origin::Environment("hmac_secret".to_string()), type_name: "[u8; 2]", source: "expected 2 bytes, got 4".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[test]
+    fn test_not_bytes() {
+        trybuild::TestCases::new().compile_fail("ui/encoding_*.rs");
+    }
+}
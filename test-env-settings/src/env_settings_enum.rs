@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::{EnvSettings, EnvSettingsEnum};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettingsEnum, PartialEq)]
+    enum LogFormat {
+        Json,
+        PrettyText,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        log_format: LogFormat,
+    }
+
+    #[rstest]
+    #[case("Json", Ok(LogFormat::Json))]
+    #[case("json", Ok(LogFormat::Json))]
+    #[case("JSON", Ok(LogFormat::Json))]
+    #[case("PrettyText", Ok(LogFormat::PrettyText))]
+    #[case("pretty-text", Ok(LogFormat::PrettyText))]
+    #[case("PRETTY_TEXT", Ok(LogFormat::PrettyText))]
+    #[case("xml", Err(env_settings_utils::ParseEnumError { variants: LogFormat::VARIANTS }))]
+    fn test_from_str(
+        #[case] value: &str,
+        #[case] expected_result: Result<LogFormat, env_settings_utils::ParseEnumError>,
+    ) {
+        assert_eq!(value.parse::<LogFormat>(), expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("log_format", "pretty_text")]),
+        Ok(TestEnvSettings { log_format: LogFormat::PrettyText })
+    )]
+    #[case(
+        HashMap::from([("log_format", "xml")]),
+        Err(EnvSettingsError::Convert { field: "log_format", value: "xml".to_string(), origin: Origin::Environment("log_format".to_string()), type_name: "LogFormat", source: "expected one of `Json`, `PrettyText`".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+}
@@ -0,0 +1,289 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::{with_env_file_variables, with_env_variables};
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{
+        EnvSettingsError, EnvSettingsResult, Explanation, Origin, Resolution,
+    };
+    use rstest::rstest;
+    use std::{cell::RefCell, collections::HashMap, path};
+
+    const FILE_PATH: &str = "explain.env";
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, file_path = "explain.env", prefix = "EXPLAIN_")]
+    struct TestEnvSettings {
+        host: String,
+
+        port: u16,
+
+        #[env_settings(default = 30)]
+        timeout: u64,
+
+        #[env_settings(secret)]
+        password: String,
+
+        log_level: Option<String>,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, file_path = "explain.env", prefix = "EXPLAIN_")]
+    struct TestReservedEnvSettings {
+        explanation: String,
+
+        origin: u16,
+
+        env_file: Option<String>,
+
+        base_dir: Option<String>,
+
+        value: u8,
+
+        result: bool,
+    }
+
+    /// Resolve the settings and check the explanation, whose file paths are checked apart since they are absolute
+    fn assert_explanation(
+        resolve: impl Fn() -> (EnvSettingsResult<TestEnvSettings>, Explanation),
+        env_variables: HashMap<&'static str, &'static str>,
+        env_file_variables: HashMap<&'static str, &'static str>,
+        expected_result: EnvSettingsResult<TestEnvSettings>,
+        expected_resolutions: Vec<Resolution>,
+    ) {
+        let explanation = RefCell::new(Explanation::new());
+        let resolve = || {
+            let (result, actual_explanation) = resolve();
+            explanation.replace(actual_explanation);
+            result
+        };
+        let _ = with_env_file_variables(
+            FILE_PATH,
+            &env_file_variables,
+            || with_env_variables(&env_variables, resolve, &expected_result),
+            &expected_result,
+        );
+        let actual_resolutions = explanation
+            .borrow()
+            .resolutions()
+            .iter()
+            .cloned()
+            .map(|mut resolution| {
+                if let Origin::File { path, .. } = &mut resolution.origin {
+                    assert!(path.is_absolute() && path.ends_with(FILE_PATH));
+                    *path = path::PathBuf::from(FILE_PATH).into();
+                }
+                resolution
+            })
+            .collect::<Vec<Resolution>>();
+        assert_eq!(actual_resolutions, expected_resolutions);
+    }
+
+    fn resolution(field: &'static str, origin: Origin, value: Option<&str>) -> Resolution {
+        Resolution {
+            field,
+            origin,
+            value: value.map(str::to_string),
+        }
+    }
+
+    fn file_origin(variable: &str) -> Origin {
+        Origin::File {
+            variable: variable.to_string(),
+            path: path::PathBuf::from(FILE_PATH).into(),
+            line: 1,
+        }
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("EXPLAIN_port", "8080"), ("EXPLAIN_password", "hunter2")]),
+        HashMap::from([("EXPLAIN_host", "localhost")]),
+        Ok(TestEnvSettings { host: "localhost".to_string(), port: 8080, timeout: 30, password: "hunter2".to_string(), log_level: None }),
+        vec![
+            resolution("host", file_origin("EXPLAIN_host"), Some("localhost")),
+            resolution("port", Origin::Environment("EXPLAIN_port".to_string()), Some("8080")),
+            resolution("timeout", Origin::Default, Some("30")),
+            resolution("password", Origin::Environment("EXPLAIN_password".to_string()), Some("***")),
+            resolution("log_level", Origin::Unset, None),
+        ]
+    )]
+    #[case(
+        HashMap::from([("EXPLAIN_host", "localhost"), ("EXPLAIN_port", "other"), ("EXPLAIN_log_level", "info")]),
+        HashMap::from([("EXPLAIN_password", "hunter2")]),
+        Err(EnvSettingsError::Convert {
+            field: "port",
+            value: "other".to_string(),
+            origin: Origin::Environment("EXPLAIN_port".to_string()),
+            type_name: "u16",
+            source: "invalid digit found in string".into(),
+            snippet: None,
+        }),
+        vec![
+            resolution("host", Origin::Environment("EXPLAIN_host".to_string()), Some("localhost")),
+            resolution("port", Origin::Environment("EXPLAIN_port".to_string()), Some("other")),
+            resolution("timeout", Origin::Default, Some("30")),
+            resolution("password", file_origin("EXPLAIN_password"), Some("***")),
+            resolution("log_level", Origin::Environment("EXPLAIN_log_level".to_string()), Some("info")),
+        ]
+    )]
+    #[case(
+        HashMap::from([("EXPLAIN_host", "localhost"), ("EXPLAIN_password", "hunter2")]),
+        HashMap::from([("EXPLAIN_port", "other")]),
+        Err(EnvSettingsError::Convert {
+            field: "port",
+            value: "other".to_string(),
+            origin: Origin::File {
+                variable: "EXPLAIN_port".to_string(),
+                path: std::fs::canonicalize(".").unwrap().join(FILE_PATH).into(),
+                line: 1,
+            },
+            type_name: "u16",
+            source: "invalid digit found in string".into(),
+            snippet: None,
+        }),
+        vec![
+            resolution("host", Origin::Environment("EXPLAIN_host".to_string()), Some("localhost")),
+            resolution("port", file_origin("EXPLAIN_port"), Some("other")),
+            resolution("timeout", Origin::Default, Some("30")),
+            resolution("password", Origin::Environment("EXPLAIN_password".to_string()), Some("***")),
+            resolution("log_level", Origin::Unset, None),
+        ]
+    )]
+    fn test_from_env_explain(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] env_file_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+        #[case] expected_resolutions: Vec<Resolution>,
+    ) {
+        assert_explanation(
+            TestEnvSettings::from_env_explained,
+            env_variables,
+            env_file_variables,
+            expected_result,
+            expected_resolutions,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("EXPLAIN_host", "localhost"), ("EXPLAIN_password", "hunter2")]),
+        HashMap::from([]),
+        Ok(TestEnvSettings { host: "localhost".to_string(), port: 8080, timeout: 60, password: "hunter2".to_string(), log_level: None }),
+        vec![
+            resolution("host", Origin::Environment("EXPLAIN_host".to_string()), Some("localhost")),
+            resolution("port", Origin::Argument, None),
+            resolution("timeout", Origin::Argument, None),
+            resolution("password", Origin::Environment("EXPLAIN_password".to_string()), Some("***")),
+            resolution("log_level", Origin::Unset, None),
+        ]
+    )]
+    fn test_new_explain(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] env_file_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+        #[case] expected_resolutions: Vec<Resolution>,
+    ) {
+        assert_explanation(
+            || TestEnvSettings::new_explained(None, Some(8080), Some(60), None, None),
+            env_variables,
+            env_file_variables,
+            expected_result,
+            expected_resolutions,
+        );
+    }
+
+    #[test]
+    fn test_from_env_snippet() {
+        let env_variables = HashMap::from([
+            ("EXPLAIN_host", "localhost"),
+            ("EXPLAIN_password", "hunter2"),
+        ]);
+        let expected_result = Err(EnvSettingsError::Convert {
+            field: "port",
+            value: "other".to_string(),
+            origin: Origin::File {
+                variable: "EXPLAIN_port".to_string(),
+                path: std::fs::canonicalize(".").unwrap().join(FILE_PATH).into(),
+                line: 1,
+            },
+            type_name: "u16",
+            source: "invalid digit found in string".into(),
+            snippet: None,
+        });
+        let actual_result = with_env_file_variables(
+            FILE_PATH,
+            &HashMap::from([("EXPLAIN_port", "other")]),
+            || with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result),
+            &expected_result,
+        );
+        // the line is kept by the error, even if the file has been removed
+        let Err(EnvSettingsError::Convert {
+            snippet: Some(snippet),
+            ..
+        }) = actual_result
+        else {
+            panic!("Snippet not found!");
+        };
+        assert_eq!(
+            (snippet.number, snippet.text.as_str()),
+            (1, "EXPLAIN_port=other")
+        );
+    }
+
+    #[test]
+    fn test_new_explain_reserved_names() {
+        let env_variables = HashMap::from([("EXPLAIN_origin", "8080"), ("EXPLAIN_value", "42")]);
+        let env_file_variables = HashMap::from([("EXPLAIN_result", "true")]);
+        let expected_result = Ok(TestReservedEnvSettings {
+            explanation: "lorem".to_string(),
+            origin: 8080,
+            env_file: None,
+            base_dir: Some("ipsum".to_string()),
+            value: 42,
+            result: true,
+        });
+        let explanation = RefCell::new(Explanation::new());
+        let resolve = || {
+            let (result, actual_explanation) = TestReservedEnvSettings::new_explained(
+                Some("lorem".to_string()),
+                None,
+                None,
+                Some("ipsum".to_string()),
+                None,
+                None,
+            );
+            explanation.replace(actual_explanation);
+            result
+        };
+        let _ = with_env_file_variables(
+            FILE_PATH,
+            &env_file_variables,
+            || with_env_variables(&env_variables, resolve, &expected_result),
+            &expected_result,
+        );
+        let actual_variables = explanation
+            .borrow()
+            .resolutions()
+            .iter()
+            .map(|resolution| {
+                (
+                    resolution.field,
+                    resolution.origin.variable().map(str::to_string),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual_variables,
+            vec![
+                ("explanation", None),
+                ("origin", Some("EXPLAIN_origin".to_string())),
+                ("env_file", None),
+                ("base_dir", None),
+                ("value", Some("EXPLAIN_value".to_string())),
+                ("result", Some("EXPLAIN_result".to_string())),
+            ]
+        );
+    }
+}
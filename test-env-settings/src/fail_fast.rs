@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        name: String,
+        age: u8,
+        height: u8,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, fail_fast)]
+    struct TestFailFastEnvSettings {
+        name: String,
+        age: u8,
+        height: u8,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("height", "180")]),
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
+    )]
+    #[case(
+        HashMap::from([("age", "other")]),
+        Err(EnvSettingsError::Multiple(vec![
+            EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None },
+            EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None },
+            EnvSettingsError::NotExists { field: "height", variables: vec!["height".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None },
+        ]))
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "42")]),
+        Err(EnvSettingsError::NotExists { field: "height", variables: vec!["height".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "42"), ("height", "180")]),
+        Ok(TestEnvSettings { name: "lorem".to_string(), age: 42, height: 180 })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("age", "42")]),
+        None,
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "height", variables: vec!["height".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
+    )]
+    #[case(
+        HashMap::from([("name", "lorem")]),
+        Some(42),
+        Err(EnvSettingsError::NotExists { field: "height", variables: vec!["height".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    fn test_new(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] age: Option<u8>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            || TestEnvSettings::new(None, age, None),
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("height", "180")]),
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "other")]),
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "42"), ("height", "180")]),
+        Ok(TestFailFastEnvSettings { name: "lorem".to_string(), age: 42, height: 180 })
+    )]
+    fn test_from_env_fail_fast(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestFailFastEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestFailFastEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+}
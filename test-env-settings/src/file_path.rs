@@ -47,7 +47,8 @@ mod tests {
                 dotenvy::Error::Io(io::Error::new(
                     io::ErrorKind::NotFound,
                     "No such file or directory (os error 2)"
-                ))
+                )),
+                None
             )
         ),
     )]
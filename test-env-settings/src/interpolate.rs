@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::{with_env_file_variables, with_env_variables};
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    const FILE_PATH: &str = "test.env";
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, interpolate)]
+    struct TestEnvSettings {
+        database_url: String,
+
+        #[env_settings(default = "${CACHE_HOME:-/tmp}/app")]
+        cache_dir: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, file_path = "test.env", interpolate)]
+    struct TestFileEnvSettings {
+        database_url: String,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([
+            ("database_url", "postgres://${DB_USER}@${DB_HOST}/app"),
+            ("DB_USER", "lorem"),
+            ("DB_HOST", "localhost"),
+        ]),
+        Ok(TestEnvSettings {
+            database_url: "postgres://lorem@localhost/app".to_string(),
+            cache_dir: "/tmp/app".to_string(),
+        })
+    )]
+    #[case(
+        HashMap::from([("database_url", "sqlite://"), ("CACHE_HOME", "/cache")]),
+        Ok(TestEnvSettings {
+            database_url: "sqlite://".to_string(),
+            cache_dir: "/cache/app".to_string(),
+        })
+    )]
+    #[case(
+        HashMap::from([("database_url", "postgres://${DB_USER}@localhost/app")]),
+        Err(EnvSettingsError::InterpolationNotExists("DB_USER".to_string()))
+    )]
+    #[case(
+        HashMap::from([("database_url", "${DB_USER}"), ("DB_USER", "${database_url}")]),
+        Err(EnvSettingsError::InterpolationCycle(vec![
+            "DB_USER".to_string(),
+            "database_url".to_string(),
+            "DB_USER".to_string(),
+        ]))
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        FILE_PATH,
+        HashMap::from([("DB_HOST", "localhost")]),
+        HashMap::from([("database_url", "'postgres://${DB_HOST}/app'")]),
+        Ok(TestFileEnvSettings { database_url: "postgres://localhost/app".to_string() })
+    )]
+    fn test_from_env_with_file(
+        #[case] file_path: &str,
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] env_file_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestFileEnvSettings>,
+    ) {
+        let _ = with_env_file_variables(
+            file_path,
+            &env_file_variables,
+            || {
+                with_env_variables(
+                    &env_variables,
+                    TestFileEnvSettings::from_env,
+                    &expected_result,
+                )
+            },
+            &expected_result,
+        );
+    }
+
+    #[test]
+    fn test_compile_time_not_exists() {
+        trybuild::TestCases::new().compile_fail("ui/interpolate_not_exists.rs");
+    }
+}
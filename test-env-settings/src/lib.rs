@@ -6,13 +6,31 @@
 
 //! # Env Settings Test Library
 
+mod alias;
 mod basic;
+mod byte_size;
 mod case_insensitive;
+mod compile_time;
+mod date_time;
 mod default;
+mod delimited;
+mod dsn;
+mod duration;
 mod e2e;
+mod empty;
+mod encoding;
+mod env_settings_enum;
+mod explain;
+mod fail_fast;
 mod file_path;
+mod interpolate;
 mod option;
+mod os_string;
+mod path;
+mod pointer;
 mod prefix;
+mod rename_all;
+mod secret;
 mod skip;
 mod variable;
 
@@ -78,10 +96,11 @@ mod tests {
         expected_result: &EnvSettingsResult<T>,
     ) {
         if expected_result.is_err() {
-            assert_eq!(
-                actual_result.as_ref().unwrap_err().to_string(),
-                expected_result.as_ref().unwrap_err().to_string()
-            );
+            let actual_error = actual_result.as_ref().unwrap_err();
+            let expected_error = expected_result.as_ref().unwrap_err();
+            assert_eq!(actual_error.to_string(), expected_error.to_string());
+            // the message does not include the source, which is compared with the error
+            assert_eq!(actual_error, expected_error);
         } else {
             assert_eq!(
                 actual_result.as_ref().unwrap(),
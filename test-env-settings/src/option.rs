@@ -4,7 +4,7 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
     use rstest::rstest;
     use std::collections::HashMap;
 
@@ -18,7 +18,7 @@ mod tests {
     #[rstest]
     #[case(
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
@@ -26,7 +26,7 @@ mod tests {
     )]
     #[case(
         HashMap::from([("age", "42")]),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -34,7 +34,7 @@ mod tests {
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "other")]),
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_from_env(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -48,7 +48,7 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem"), ("age", "42")]),
@@ -60,7 +60,7 @@ mod tests {
         HashMap::from([]),
         None,
         Some(42),
-        Err(EnvSettingsError::NotExists("name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "lorem")]),
@@ -96,7 +96,7 @@ mod tests {
         HashMap::from([("name", "lorem"), ("age", "other")]),
         None,
         None,
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_new(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -110,4 +110,40 @@ mod tests {
             &expected_result,
         );
     }
+
+    type MaybeAge = Option<u8>;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestQualifiedEnvSettings {
+        name: std::option::Option<String>,
+        age: core::option::Option<u8>,
+
+        #[env_settings(optional)]
+        alias_age: MaybeAge,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Ok(TestQualifiedEnvSettings { name: None, age: None, alias_age: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "42"), ("alias_age", "24")]),
+        Ok(TestQualifiedEnvSettings { name: Some("lorem".to_string()), age: Some(42), alias_age: Some(24) })
+    )]
+    #[case(
+        HashMap::from([("alias_age", "other")]),
+        Err(EnvSettingsError::Convert { field: "alias_age", value: "other".to_string(), origin: Origin::Environment("alias_age".to_string()), type_name: "MaybeAge", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    fn test_from_env_qualified(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestQualifiedEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestQualifiedEnvSettings::from_env,
+            &expected_result,
+        );
+    }
 }
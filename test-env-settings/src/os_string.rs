@@ -0,0 +1,76 @@
+#[cfg(all(test, unix))]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::PathBuf;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        locale_name: Option<String>,
+        locale_raw: OsString,
+        locale_path: Option<PathBuf>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([
+            ("locale_raw", OsStr::from_bytes(b"caf\xe9")),
+            ("locale_path", OsStr::from_bytes(b"/tmp/caf\xe9")),
+        ]),
+        Ok(TestEnvSettings {
+            locale_name: None,
+            locale_raw: OsStr::from_bytes(b"caf\xe9").to_os_string(),
+            locale_path: Some(PathBuf::from(OsStr::from_bytes(b"/tmp/caf\xe9"))),
+        })
+    )]
+    #[case(
+        HashMap::from([
+            ("locale_name", OsStr::new("café")),
+            ("locale_raw", OsStr::new("café")),
+        ]),
+        Ok(TestEnvSettings {
+            locale_name: Some("café".to_string()),
+            locale_raw: OsString::from("café"),
+            locale_path: None,
+        })
+    )]
+    #[case(
+        HashMap::from([
+            ("locale_name", OsStr::from_bytes(b"caf\xe9")),
+            ("locale_raw", OsStr::new("café")),
+        ]),
+        Err(EnvSettingsError::NotUnicode("locale_name".to_string()))
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static OsStr>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[test]
+    fn test_compile_time_not_unicode() {
+        unsafe {
+            std::env::set_var(
+                "TEST_ENV_SETTINGS_NOT_UNICODE",
+                OsStr::from_bytes(b"ab\xff"),
+            );
+        }
+        {
+            let test_cases = trybuild::TestCases::new();
+            test_cases.pass("ui/os_string_not_unicode.rs");
+            test_cases.compile_fail("ui/string_not_unicode.rs");
+        }
+        unsafe {
+            std::env::remove_var("TEST_ENV_SETTINGS_NOT_UNICODE");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::{with_env_file_variables, with_env_variables};
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::EnvSettingsResult;
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::{env, path::PathBuf};
+
+    const FILE_PATH: &str = "test.env";
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, file_path = "test.env")]
+    struct TestEnvSettings {
+        #[env_settings(relative_to_file)]
+        cert_path: PathBuf,
+
+        #[env_settings(default = "~/.cache")]
+        cache_path: std::path::PathBuf,
+    }
+
+    fn get_current_dir() -> PathBuf {
+        env::current_dir().unwrap().canonicalize().unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        FILE_PATH,
+        HashMap::from([]),
+        HashMap::from([("cert_path", "certs/server.pem")]),
+        Ok(TestEnvSettings {
+            cert_path: get_current_dir().join("certs/server.pem"),
+            cache_path: env::home_dir().unwrap().join(".cache"),
+        })
+    )]
+    #[case(
+        FILE_PATH,
+        HashMap::from([("cert_path", "certs/server.pem"), ("cache_path", "/cache")]),
+        HashMap::from([("cert_path", "other.pem")]),
+        Ok(TestEnvSettings {
+            cert_path: PathBuf::from("certs/server.pem"),
+            cache_path: PathBuf::from("/cache"),
+        })
+    )]
+    #[case(
+        FILE_PATH,
+        HashMap::from([]),
+        HashMap::from([("cert_path", "/certs/server.pem"), ("cache_path", "~")]),
+        Ok(TestEnvSettings {
+            cert_path: PathBuf::from("/certs/server.pem"),
+            cache_path: env::home_dir().unwrap(),
+        })
+    )]
+    fn test_from_env(
+        #[case] file_path: &str,
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] env_file_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_file_variables(
+            file_path,
+            &env_file_variables,
+            || with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result),
+            &expected_result,
+        );
+    }
+}
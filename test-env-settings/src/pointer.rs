@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        name: Arc<String>,
+
+        #[env_settings(default = 24)]
+        age: Box<u8>,
+
+        nickname: Option<Rc<String>>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem")]),
+        Ok(TestEnvSettings { name: Arc::new("lorem".to_string()), age: Box::new(24), nickname: None })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "42"), ("nickname", "ipsum")]),
+        Ok(TestEnvSettings { name: Arc::new("lorem".to_string()), age: Box::new(42), nickname: Some(Rc::new("ipsum".to_string())) })
+    )]
+    #[case(
+        HashMap::from([("name", "lorem"), ("age", "other")]),
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("name", "lorem")]),
+        Some(Arc::new("ipsum".to_string())),
+        Some(Box::new(42)),
+        Ok(TestEnvSettings { name: Arc::new("ipsum".to_string()), age: Box::new(42), nickname: None })
+    )]
+    fn test_new(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] name: Option<Arc<String>>,
+        #[case] age: Option<Box<u8>>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            || TestEnvSettings::new(name.clone(), age.clone(), None),
+            &expected_result,
+        );
+    }
+}
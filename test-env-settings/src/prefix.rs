@@ -4,7 +4,7 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching};
     use rstest::rstest;
     use std::collections::HashMap;
 
@@ -15,18 +15,40 @@ mod tests {
         age: u8,
     }
 
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, prefix_from_struct, rename_all = "SCREAMING_SNAKE_CASE")]
+    struct HttpServerSettings {
+        port: u16,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, prefix_from_struct)]
+    struct HTTPClientConfig {
+        timeout: u16,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, prefix_from_struct)]
+    struct Settings {
+        retries: u16,
+    }
+
     #[rstest]
     #[case(
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("TEST_name"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["TEST_age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("TEST_name", "lorem")]),
-        Err(EnvSettingsError::NotExists("TEST_age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["TEST_age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("TEST_age", "42")]),
-        Err(EnvSettingsError::NotExists("TEST_name"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("TEST_nam", "lorem"), ("TEST_age", "42")]),
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("TEST_nam".to_string()) })
     )]
     #[case(
         HashMap::from([("TEST_name", "lorem"), ("TEST_age", "42")]),
@@ -44,13 +66,13 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("TEST_name"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["TEST_age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("TEST_name", "other")]),
         Some("lorem".to_string()),
         None,
-        Err(EnvSettingsError::NotExists("TEST_age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["TEST_age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("TEST_age", "42")]),
@@ -76,4 +98,38 @@ mod tests {
             &expected_result,
         );
     }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("HTTP_SERVER_port", "8080"), ("HTTP_SERVER_SETTINGS_PORT", "8080")]),
+        || HttpServerSettings::from_env().map(|settings| settings.port),
+        Err(EnvSettingsError::NotExists { field: "port", variables: vec!["HTTP_SERVER_PORT".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("HTTP_SERVER_port".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("HTTP_SERVER_PORT", "8080")]),
+        || HttpServerSettings::from_env().map(|settings| settings.port),
+        Ok(8080)
+    )]
+    #[case(
+        HashMap::from([("HTTP_CLIENT_timeout", "30")]),
+        || HTTPClientConfig::from_env().map(|settings| settings.timeout),
+        Ok(30)
+    )]
+    #[case(
+        HashMap::from([("SETTINGS_retries", "3")]),
+        || Settings::from_env().map(|settings| settings.retries),
+        Ok(3)
+    )]
+    fn test_from_env_prefix_from_struct(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] from_env: fn() -> EnvSettingsResult<u16>,
+        #[case] expected_result: EnvSettingsResult<u16>,
+    ) {
+        let _ = with_env_variables(&env_variables, from_env, &expected_result);
+    }
+
+    #[test]
+    fn test_prefix_from_struct_value() {
+        trybuild::TestCases::new().compile_fail("ui/prefix_from_struct_value.rs");
+    }
 }
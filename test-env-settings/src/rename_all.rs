@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching};
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, prefix = "APP_", rename_all = "SCREAMING_SNAKE_CASE")]
+    struct TestEnvSettings {
+        database_url: String,
+
+        max_connections: u8,
+
+        #[env_settings(variable = "app_log_level")]
+        log_level: Option<String>,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "kebab-case")]
+    struct TestKebabEnvSettings {
+        kebab_case_name: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "camelCase")]
+    struct TestCamelEnvSettings {
+        camel_case_name: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "PascalCase")]
+    struct TestPascalEnvSettings {
+        pascal_case_name: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "lowercase")]
+    struct TestLowerEnvSettings {
+        database_url: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "UPPERCASE")]
+    struct TestUpperEnvSettings {
+        database_url: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "snake_case")]
+    struct TestSnakeEnvSettings {
+        database_url: String,
+    }
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay, rename_all = "SCREAMING-KEBAB-CASE")]
+    struct TestScreamingKebabEnvSettings {
+        database_url: String,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "database_url", variables: vec!["APP_DATABASE_URL".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "max_connections", variables: vec!["APP_MAX_CONNECTIONS".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
+    )]
+    #[case(
+        HashMap::from([("APP_database_url", "lorem"), ("APP_max_connections", "42")]),
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "database_url", variables: vec!["APP_DATABASE_URL".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("APP_database_url".to_string()) }, EnvSettingsError::NotExists { field: "max_connections", variables: vec!["APP_MAX_CONNECTIONS".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("APP_max_connections".to_string()) }]))
+    )]
+    #[case(
+        HashMap::from([("APP_DATABASE_URL", "lorem"), ("APP_MAX_CONNECTIONS", "42")]),
+        Ok(TestEnvSettings { database_url: "lorem".to_string(), max_connections: 42, log_level: None })
+    )]
+    #[case(
+        HashMap::from([("APP_DATABASE_URL", "lorem"), ("APP_MAX_CONNECTIONS", "42"), ("APP_LOG_LEVEL", "info")]),
+        Ok(TestEnvSettings { database_url: "lorem".to_string(), max_connections: 42, log_level: None })
+    )]
+    #[case(
+        HashMap::from([("APP_DATABASE_URL", "lorem"), ("APP_MAX_CONNECTIONS", "42"), ("app_log_level", "info")]),
+        Ok(TestEnvSettings { database_url: "lorem".to_string(), max_connections: 42, log_level: Some("info".to_string()) })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("kebab_case_name", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "kebab_case_name", variables: vec!["kebab-case-name".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("kebab_case_name".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("kebab-case-name", "lorem")]),
+        Ok(TestKebabEnvSettings { kebab_case_name: "lorem".to_string() })
+    )]
+    fn test_from_env_kebab_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestKebabEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestKebabEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("camel_case_name", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "camel_case_name", variables: vec!["camelCaseName".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("camel_case_name".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("camelCaseName", "lorem")]),
+        Ok(TestCamelEnvSettings { camel_case_name: "lorem".to_string() })
+    )]
+    fn test_from_env_camel_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestCamelEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestCamelEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("PascalCaseName", "lorem")]),
+        Ok(TestPascalEnvSettings { pascal_case_name: "lorem".to_string() })
+    )]
+    fn test_from_env_pascal_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestPascalEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestPascalEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("DATABASE_URL", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "database_url", variables: vec!["database_url".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("DATABASE_URL".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("database_url", "lorem")]),
+        Ok(TestLowerEnvSettings { database_url: "lorem".to_string() })
+    )]
+    fn test_from_env_lower_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestLowerEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestLowerEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("database_url", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "database_url", variables: vec!["DATABASE_URL".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("database_url".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("DATABASE_URL", "lorem")]),
+        Ok(TestUpperEnvSettings { database_url: "lorem".to_string() })
+    )]
+    fn test_from_env_upper_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestUpperEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestUpperEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("DATABASE_URL", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "database_url", variables: vec!["database_url".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("DATABASE_URL".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("database_url", "lorem")]),
+        Ok(TestSnakeEnvSettings { database_url: "lorem".to_string() })
+    )]
+    fn test_from_env_snake_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestSnakeEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestSnakeEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([("DATABASE_URL", "lorem")]),
+        Err(EnvSettingsError::NotExists { field: "database_url", variables: vec!["DATABASE-URL".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: Some("DATABASE_URL".to_string()) })
+    )]
+    #[case(
+        HashMap::from([("DATABASE-URL", "lorem")]),
+        Ok(TestScreamingKebabEnvSettings { database_url: "lorem".to_string() })
+    )]
+    fn test_from_env_screaming_kebab_case(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestScreamingKebabEnvSettings>,
+    ) {
+        let _ = with_env_variables(
+            &env_variables,
+            TestScreamingKebabEnvSettings::from_env,
+            &expected_result,
+        );
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+
+    use crate::tests::with_env_variables;
+
+    use env_settings_derive::EnvSettings;
+    use env_settings_utils::{
+        EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin, Secret,
+    };
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    #[derive(Debug, EnvSettings, PartialEq)]
+    #[env_settings(delay)]
+    struct TestEnvSettings {
+        password: Secret<String>,
+
+        pin: Option<Secret<u16>>,
+
+        #[env_settings(secret)]
+        credentials: Option<(String, u16)>,
+    }
+
+    #[rstest]
+    #[case(
+        HashMap::from([]),
+        Err(EnvSettingsError::NotExists { field: "password", variables: vec!["password".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
+    )]
+    #[case(
+        HashMap::from([("password", "hunter2"), ("pin", "1234"), ("credentials", "lorem:42")]),
+        Ok(TestEnvSettings { password: Secret::new("hunter2".to_string()), pin: Some(Secret::new(1234)), credentials: Some(("lorem".to_string(), 42)) })
+    )]
+    #[case(
+        HashMap::from([("password", "hunter2"), ("pin", "12a4")]),
+        Err(EnvSettingsError::Convert { field: "pin", value: "***".to_string(), origin: Origin::Environment("pin".to_string()), type_name: "Secret", source: "invalid digit found in string".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("password", "hunter2"), ("credentials", "lorem:ipsum")]),
+        Err(EnvSettingsError::Convert { field: "credentials", value: "***".to_string(), origin: Origin::Environment("credentials".to_string()), type_name: "(String, u16)", source: "invalid value `***` at position 1: invalid digit found in string".into(), snippet: None })
+    )]
+    #[case(
+        HashMap::from([("password", "hunter2"), ("credentials", "lorem")]),
+        Err(EnvSettingsError::Convert { field: "credentials", value: "***".to_string(), origin: Origin::Environment("credentials".to_string()), type_name: "(String, u16)", source: "expected 2 values separated by `:`, got 1".into(), snippet: None })
+    )]
+    fn test_from_env(
+        #[case] env_variables: HashMap<&'static str, &'static str>,
+        #[case] expected_result: EnvSettingsResult<TestEnvSettings>,
+    ) {
+        let _ = with_env_variables(&env_variables, TestEnvSettings::from_env, &expected_result);
+    }
+
+    #[test]
+    fn test_debug() {
+        let settings = TestEnvSettings {
+            password: Secret::new("hunter2".to_string()),
+            pin: None,
+            credentials: None,
+        };
+        assert_eq!(
+            format!("{settings:?}"),
+            "TestEnvSettings { password: ***, pin: None, credentials: None }"
+        );
+    }
+}
@@ -4,7 +4,7 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching, Origin};
     use rstest::rstest;
     use std::collections::HashMap;
 
@@ -21,7 +21,7 @@ mod tests {
     #[case(
         HashMap::from([]),
 		vec!["lorem".to_string()],
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("age", "42")]),
@@ -36,7 +36,7 @@ mod tests {
     #[case(
         HashMap::from([("age", "other")]),
 		vec!["lorem".to_string()],
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_from_env(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -55,7 +55,7 @@ mod tests {
         HashMap::from([]),
         vec!["lorem".to_string()],
         None,
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("name", "[other]"), ("age", "42")]),
@@ -85,7 +85,7 @@ mod tests {
         HashMap::from([("age", "other")]),
         vec!["lorem".to_string()],
         None,
-        Err(EnvSettingsError::Convert("age", "other".to_string(), "u8"))
+        Err(EnvSettingsError::Convert { field: "age", value: "other".to_string(), origin: Origin::Environment("age".to_string()), type_name: "u8", source: "invalid digit found in string".into(), snippet: None })
     )]
     fn test_new(
         #[case] env_variables: HashMap<&'static str, &'static str>,
@@ -4,7 +4,7 @@ mod tests {
     use crate::tests::with_env_variables;
 
     use env_settings_derive::EnvSettings;
-    use env_settings_utils::{EnvSettingsError, EnvSettingsResult};
+    use env_settings_utils::{EnvSettingsError, EnvSettingsResult, EnvSource, KeyMatching};
     use rstest::rstest;
     use std::collections::HashMap;
 
@@ -19,15 +19,15 @@ mod tests {
     #[rstest]
     #[case(
         HashMap::from([]),
-        Err(EnvSettingsError::NotExists("TEST_NAME"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_NAME".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("TEST_NAME", "lorem")]),
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("age", "42")]),
-        Err(EnvSettingsError::NotExists("TEST_NAME"))
+        Err(EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_NAME".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("TEST_NAME", "lorem"), ("age", "42")]),
@@ -45,13 +45,13 @@ mod tests {
         HashMap::from([]),
         None,
         None,
-        Err(EnvSettingsError::NotExists("TEST_NAME"))
+        Err(EnvSettingsError::Multiple(vec![EnvSettingsError::NotExists { field: "name", variables: vec!["TEST_NAME".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }, EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None }]))
     )]
     #[case(
         HashMap::from([("TEST_NAME", "other")]),
         Some("lorem".to_string()),
         None,
-        Err(EnvSettingsError::NotExists("age"))
+        Err(EnvSettingsError::NotExists { field: "age", variables: vec!["age".to_string()], matching: KeyMatching::Exact, sources: vec![EnvSource::Environment], suggestion: None })
     )]
     #[case(
         HashMap::from([("age", "42")]),
@@ -0,0 +1,9 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+struct TestEnvSettings {
+    #[env_settings(default = "ab", variable = "TEST_ENV_SETTINGS_SURELY_MISSING")]
+    initial: char,
+}
+
+fn main() {}
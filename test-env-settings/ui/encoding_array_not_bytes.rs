@@ -0,0 +1,10 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+#[env_settings(delay)]
+struct TestEnvSettings {
+    #[env_settings(encoding = "hex")]
+    aes_key: [u16; 4],
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+#[env_settings(delay)]
+struct TestEnvSettings {
+    #[env_settings(encoding = "base64")]
+    signing_keys: Vec<String>,
+}
+
+fn main() {}
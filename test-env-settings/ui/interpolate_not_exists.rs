@@ -0,0 +1,10 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+#[env_settings(interpolate)]
+struct TestEnvSettings {
+    #[env_settings(default = "${SURELY_MISSING_VAR_XYZ}/app")]
+    cache_dir: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use env_settings_derive::EnvSettings;
+use std::ffi::{OsStr, OsString};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+#[derive(Debug, EnvSettings, PartialEq)]
+struct TestEnvSettings {
+    #[env_settings(variable = "TEST_ENV_SETTINGS_NOT_UNICODE")]
+    locale_raw: OsString,
+
+    #[env_settings(variable = "TEST_ENV_SETTINGS_NOT_UNICODE")]
+    locale_path: PathBuf,
+}
+
+fn main() {
+    let expected_result = TestEnvSettings {
+        locale_raw: OsStr::from_bytes(b"ab\xff").to_os_string(),
+        locale_path: PathBuf::from(OsStr::from_bytes(b"ab\xff")),
+    };
+    assert_eq!(TestEnvSettings::from_env(), Ok(expected_result));
+}
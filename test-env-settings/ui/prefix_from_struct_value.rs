@@ -0,0 +1,9 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+#[env_settings(delay, prefix_from_struct = "Settings")]
+struct HttpServerSettings {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use env_settings_derive::EnvSettings;
+
+#[derive(EnvSettings)]
+struct TestEnvSettings {
+    #[env_settings(variable = "TEST_ENV_SETTINGS_NOT_UNICODE")]
+    locale_name: String,
+}
+
+fn main() {}